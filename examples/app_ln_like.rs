@@ -8,6 +8,7 @@ extern crate fui;
 use fui::feeders::DirItems;
 use fui::fields::{Autocomplete, Checkbox, Multiselect};
 use fui::form::FormView;
+use fui::normalizers::{ExpandTilde, Trim};
 use fui::utils;
 use fui::validators::{DirExists, Required};
 use fui::{Fui, Value};
@@ -26,11 +27,15 @@ fn main() {
                 .field(
                     Autocomplete::new("TARGET", DirItems::new())
                         .help("Target of link")
+                        .normalize(Trim)
+                        .normalize(ExpandTilde)
                         .validator(Required),
                 )
                 .field(
                     Autocomplete::new("LINK_NAME", DirItems::new())
                         .help("Destiny of link")
+                        .normalize(Trim)
+                        .normalize(ExpandTilde)
                         .validator(Required),
                 )
                 .field(make_symbolic.clone().initial(true)),
@@ -48,6 +53,7 @@ fn main() {
                     Autocomplete::new("DIRECTORY", DirItems::dirs())
                         .help("Directory where all links should be stored")
                         .initial(utils::cwd())
+                        .normalize(ExpandTilde)
                         .validator(Required)
                         .validator(DirExists),
                 )
@@ -9,7 +9,7 @@ use fui::feeders::DirItems;
 use fui::fields::{Autocomplete, Multiselect};
 use fui::form::FormView;
 use fui::utils::cwd;
-use fui::validators::{FileExists, OneOf, PathFree, Required};
+use fui::validators::{DirEmpty, FileExists, OneOf, PathFree, Required, Warn, WithMessage};
 use fui::{Fui, Value};
 
 fn hdlr(v: Value) {
@@ -38,7 +38,7 @@ fn main() {
                     Autocomplete::new("target", DirItems::dirs())
                         .help("Name of archive file")
                         .validator(Required)
-                        .validator(PathFree),
+                        .validator(Warn(WithMessage(PathFree, "Archive will be overwritten"))),
                 )
                 .field(compression.clone()),
             hdlr,
@@ -56,7 +56,8 @@ fn main() {
                     Autocomplete::new("dst-dir", DirItems::dirs())
                         .initial(cwd())
                         .help("Dir where extracted files should land")
-                        .validator(Required),
+                        .validator(Required)
+                        .validator(Warn(DirEmpty)),
                 )
                 .field(compression.clone()),
             hdlr,
@@ -9,7 +9,8 @@ use cursive::traits::Boxable;
 use cursive::views::{Dialog, DummyView, LinearLayout};
 
 use fui::views::Autocomplete;
-use fui::feeders::DirItems;
+use fui::feeders::{Async, Cached, Command, DirItems, Executables, Feeder, Items, Lines,
+                   MatchStrategy, Processes, Suggestion};
 
 fn handler(c: &mut Cursive, submitted: Rc<String>) {
     let text = format!("submitted {:?}", submitted);
@@ -19,14 +20,70 @@ fn handler(c: &mut Cursive, submitted: Rc<String>) {
 
 fn main() {
     let mut c = Cursive::new();
+    // lets `Async` feeder deliver background results
+    c.set_fps(10);
 
     let layout = LinearLayout::vertical()
+        // fuzzy matches options (like `fzf`)
         .child(Autocomplete::new(vec!["option1", "option2", "option3", ".."]).on_submit(handler))
         .child(DummyView)
+        // matches only beginnings of options
+        .child(
+            Autocomplete::new(
+                Items::new(vec!["build", "bench", "check", "clean"]).strategy(MatchStrategy::Prefix),
+            ).on_submit(handler),
+        )
+        .child(DummyView)
+        // shows issue titles with categories and descriptions, submits only issue id
+        .child(
+            Autocomplete::new(vec![
+                Suggestion::new("#1234 Fix login bug")
+                    .value("1234")
+                    .category("bug")
+                    .description("open"),
+                Suggestion::new("#1250 Add dark theme")
+                    .value("1250")
+                    .category("feature")
+                    .description("in review"),
+            ]).on_submit(handler),
+        )
+        .child(DummyView)
+        // suggests tarballs from current dir followed by recently used ones
+        .child(
+            Autocomplete::new(
+                DirItems::new()
+                    .chain(vec!["backup.tar", "release.tar"])
+                    .filter(|s| s.ends_with(".tar"))
+                    .dedupe(),
+            ).on_submit(handler),
+        )
+        .child(DummyView)
+        // suggests executables found in $PATH
+        .child(Autocomplete::new(Executables::new()).on_submit(handler))
+        .child(DummyView)
+        // suggests running processes, submits pid
+        .child(Autocomplete::new(Processes).on_submit(handler))
+        .child(DummyView)
+        // suggests lines of file, changes of file are picked up
+        .child(Autocomplete::new(Lines::from_file("./Cargo.toml")).on_submit(handler))
+        .child(DummyView)
+        // suggests local git branches
+        .child(
+            Autocomplete::new(Command::new("git", vec!["branch", "--format=%(refname:short)"]))
+                .on_submit(handler),
+        )
+        .child(DummyView)
         .child(Autocomplete::new(DirItems::new()).on_submit(handler))
         .child(DummyView)
         // completes paths as absolute paths
         .child(Autocomplete::new(DirItems::new().use_full_paths()).on_submit(handler))
+        .child(DummyView)
+        // queries directories in background thread, typing isn't blocked,
+        // retyped text is served from cache
+        .child(
+            Autocomplete::new(Async::new(Cached::new(DirItems::new()), c.cb_sink().clone()))
+                .on_submit(handler),
+        )
         .child(DummyView);
 
     c.add_layer(Dialog::around(layout).full_width());
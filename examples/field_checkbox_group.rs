@@ -0,0 +1,39 @@
+// Demonstrates features of field CheckboxGroup
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::fields::CheckboxGroup;
+use fui::form::FormView;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let options = vec!["tests", "docs", "benches"];
+
+    let form = FormView::new()
+        .field(
+            CheckboxGroup::new("enable", options.clone())
+                .help("ctrl+a selects all, ctrl+n selects none"),
+        )
+        .field(
+            CheckboxGroup::new("at-least-one", options.clone())
+                .initial(vec!["tests"])
+                .min_selected(1),
+        )
+        .field(CheckboxGroup::new("at-most-two", options).max_selected(2))
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
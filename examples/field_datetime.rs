@@ -0,0 +1,37 @@
+// Demonstrates features of fields Date, Time and DateTime
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::fields::{Date, DateTime, Time};
+use fui::form::FormView;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let form = FormView::new()
+        .field(Date::new("date").help("keys left/right pick segment, up/down change it"))
+        .field(Time::new("time").initial("12:30:00"))
+        .field(DateTime::new("datetime").initial("2018-06-01T08:00:00"))
+        .field(
+            Date::new("bounded-date")
+                .help("only dates from 2018")
+                .initial("2018-06-15")
+                .min("2018-01-01")
+                .max("2018-12-31"),
+        )
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
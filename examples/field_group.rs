@@ -0,0 +1,40 @@
+// Demonstrates features of field Group
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::fields::{Checkbox, Group, Text};
+use fui::form::FormView;
+use fui::validators::Required;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let form = FormView::new()
+        .field(Text::new("name").validator(Required))
+        .field(
+            Group::new("database")
+                .help("Errors are reported like: database.port")
+                .field(Text::new("host").initial("localhost").validator(Required))
+                .field(Text::new("port").validator(Required))
+                .field(
+                    Group::new("credentials")
+                        .field(Text::new("user").validator(Required))
+                        .field(Checkbox::new("ask-password")),
+                ),
+        )
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
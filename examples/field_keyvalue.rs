@@ -0,0 +1,37 @@
+// Demonstrates features of field KeyValue
+extern crate cursive;
+extern crate fui;
+extern crate regex;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use regex::Regex;
+use serde_json::value::Value;
+
+use fui::fields::KeyValue;
+use fui::form::FormView;
+use fui::validators::Required;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let form = FormView::new()
+        .field(KeyValue::new("labels").help("Duplicated keys are reported"))
+        .field(
+            KeyValue::new("env-vars")
+                .initial(vec![("RUST_LOG", "debug")])
+                .key_validator(Regex::new("^[A-Z_][A-Z0-9_]*$").unwrap())
+                .value_validator(Required),
+        )
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
@@ -0,0 +1,41 @@
+// Demonstrates features of field List
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::feeders::DirItems;
+use fui::fields::{Autocomplete, List, Text};
+use fui::form::FormView;
+use fui::validators::{FileExists, Required};
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let form = FormView::new()
+        .field(
+            List::new("env-vars", Text::new("var").validator(Required))
+                .help("Rows are added with <Add> and removed with <Remove>")
+                .initial(vec!["RUST_LOG=debug"]),
+        )
+        .field(
+            List::new(
+                "paths",
+                Autocomplete::new("path", DirItems::new()).validator(FileExists),
+            ).min_items(1)
+                .max_items(3),
+        )
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
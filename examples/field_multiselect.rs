@@ -10,7 +10,7 @@ use serde_json::value::Value;
 
 use fui::fields::Multiselect;
 use fui::form::FormView;
-use fui::validators::{OneOf, Required};
+use fui::validators::{MaxItems, MinItems, OneOf, Required};
 
 fn show_data(c: &mut Cursive, data: Value) {
     let text = format!("Got data: {:?}", data);
@@ -30,6 +30,12 @@ fn main() {
         .field(
             Multiselect::new("with-validators", options.clone()).validator(OneOf(options.clone())),
         )
+        .field(
+            Multiselect::new("with-items-count", options.clone())
+                .help("pick 2 or 3 items")
+                .validator(MinItems(2))
+                .validator(MaxItems(3)),
+        )
         .field(
             Multiselect::new("all-in-one", options.clone())
                 .help("help")
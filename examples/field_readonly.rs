@@ -0,0 +1,39 @@
+// Demonstrates fields which aren't editable: Hidden, ReadOnly and Computed
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::fields::{Autocomplete, Computed, Hidden, ReadOnly, Text};
+use fui::form::FormView;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let formats = vec!["tar", "tar.gz", "tar.bz2"];
+    let form = FormView::new()
+        .field(Hidden::new("version", 2))
+        .field(ReadOnly::new("branch", "master").help("detected git branch"))
+        .field(Text::new("name").initial("backup"))
+        .field(Autocomplete::new("format", formats).initial("tar.gz"))
+        .field(Computed::new("archive", |data| {
+            format!(
+                "{}.{}",
+                data["name"].as_str().unwrap_or(""),
+                data["format"].as_str().unwrap_or("")
+            )
+        }).help("final archive name"))
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
@@ -0,0 +1,30 @@
+// Demonstrates features of field Slider
+extern crate cursive;
+extern crate fui;
+extern crate serde_json;
+
+use cursive::Cursive;
+use cursive::traits::Boxable;
+use cursive::views::Dialog;
+use serde_json::value::Value;
+
+use fui::fields::Slider;
+use fui::form::FormView;
+
+fn show_data(c: &mut Cursive, data: Value) {
+    let text = format!("Got data: {:?}", data);
+    c.add_layer(Dialog::info(text));
+}
+
+fn main() {
+    let mut siv = Cursive::new();
+
+    let form = FormView::new()
+        .field(Slider::new("verbosity", 0, 5).help("keys left/right change value"))
+        .field(Slider::new("quality", 0, 100).step(10).initial(70))
+        .field(Slider::new("jobs", 1, 16).initial(4).help("number of parallel jobs"))
+        .on_submit(show_data);
+    siv.add_layer(Dialog::around(form).full_screen());
+
+    siv.run();
+}
@@ -12,7 +12,8 @@ use serde_json::value::Value;
 
 use fui::fields::Text;
 use fui::form::FormView;
-use fui::validators::Required;
+use fui::normalizers::{Lowercase, Trim};
+use fui::validators::{Command, Required};
 
 fn show_data(c: &mut Cursive, data: Value) {
     let text = format!("Got data: {:?}", data);
@@ -21,6 +22,9 @@ fn show_data(c: &mut Cursive, data: Value) {
 
 fn main() {
     let mut siv = Cursive::new();
+    // lets command validator deliver its result
+    siv.set_fps(10);
+    let sink = siv.cb_sink().clone();
 
     let form = FormView::new()
         .field(Text::new("basic-field"))
@@ -32,6 +36,25 @@ fn main() {
                 .validator(Required)
                 .validator(Regex::new("[0-9]").unwrap()),
         )
+        .field(Text::new("with-closure-validator").validator(|data: &str| {
+            if data.parse::<u16>().is_ok() {
+                None
+            } else {
+                Some("Value must be a port number".to_string())
+            }
+        }))
+        .field(
+            Text::new("with-command-validator")
+                .help("git branch name")
+                .validator(Command::new("git check-ref-format --branch {}").sink(sink)),
+        )
+        .field(
+            Text::new("with-normalizers")
+                .help("trimmed & lowercased")
+                .normalize(Trim)
+                .normalize(Lowercase)
+                .validator(Required),
+        )
         .field(
             Text::new("all-in-one")
                 .help("help")
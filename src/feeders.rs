@@ -1,16 +1,150 @@
 //! Data providers for `views` with suggestion feature (like `Autocomplete`, `Multiselect`).
 
+use cursive::CbFunc;
 use glob::{glob_with, MatchOptions};
+use std::collections::HashMap;
+use serde_json::Value;
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::fmt::Display;
 use std::rc::Rc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use utils::{expand_tilde, run_command};
+
+/// Item suggested by `Feeder`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Suggestion};
+/// # fn main() {
+/// let issues = vec![
+///     Suggestion::new("#1234 Fix login bug")
+///         .value("1234")
+///         .description("open")
+///         .category("bug"),
+///     Suggestion::new("#1250 Add dark theme").value("1250"),
+/// ];
+/// assert_eq!(issues.query("login", 0, 10), vec!["1234"]);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    /// Text shown to user.
+    pub label: String,
+    /// Text submitted when suggestion is picked.
+    pub value: String,
+    /// Text shown (dimmed) next to `label`, empty if missing.
+    pub description: String,
+    /// Name of suggestion's group shown before `label`, empty if missing.
+    pub category: String,
+    /// Positions (char indices) of `label`'s characters which matched query.
+    pub positions: Vec<usize>,
+}
+
+impl Suggestion {
+    /// Creates a new `Suggestion` which `value` is `label`.
+    pub fn new<IS: Into<String>>(label: IS) -> Self {
+        let label = label.into();
+        Suggestion {
+            value: label.clone(),
+            label,
+            description: String::new(),
+            category: String::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// Sets value submitted when suggestion is picked.
+    pub fn value<IS: Into<String>>(mut self, value: IS) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets description shown next to label.
+    pub fn description<IS: Into<String>>(mut self, description: IS) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Sets category shown before label.
+    pub fn category<IS: Into<String>>(mut self, category: IS) -> Self {
+        self.category = category.into();
+        self
+    }
+
+    /// Sets positions of matched characters.
+    pub fn positions(mut self, positions: Vec<usize>) -> Self {
+        self.positions = positions;
+        self
+    }
+}
 
 /// Makes data querable.
 pub trait Feeder: 'static {
     /// Returns data filtered by `text`, `position` limited to `items_count`.
     fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String>;
+    /// Returns suggestions like `query` does, including positions of matched characters.
+    ///
+    /// By default suggestions have no matched characters.
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        self.query(text, position, items_count)
+            .into_iter()
+            .map(Suggestion::new)
+            .collect()
+    }
+    /// Checks if results changed since they were returned last time (like when background
+    /// query finished), views should query the feeder again then.
+    fn is_updated(&self) -> bool {
+        false
+    }
+    /// Suggests items of this feeder followed by items of `other`.
+    fn chain<F: Feeder>(self, other: F) -> Chain<Self, F>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+    /// Transforms suggestions with `map`.
+    fn map<M>(self, map: M) -> Map<Self, M>
+    where
+        Self: Sized,
+        M: Fn(Suggestion) -> Suggestion + 'static,
+    {
+        Map {
+            feeder: self,
+            map,
+        }
+    }
+    /// Suggests only items which values satisfy `predicate`.
+    ///
+    /// Only the first 10000 items of this feeder are considered.
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&str) -> bool + 'static,
+    {
+        Filter {
+            feeder: self,
+            predicate,
+        }
+    }
+    /// Suggests only the first of items with the same value.
+    ///
+    /// Only the first 10000 items of this feeder are considered.
+    fn dedupe(self) -> Dedupe<Self>
+    where
+        Self: Sized,
+    {
+        Dedupe { feeder: self }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -85,12 +219,11 @@ fn add_glob<P: AsRef<str>>(path: P) -> String {
 }
 
 impl Feeder for DirItems {
-    fn query(&self, text: &str, _position: isize, items_count: usize) -> Vec<String> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
         let path = if text == "" {
             format!("./")
         } else if text.starts_with('~') {
-            let path = text.replace("~", env::home_dir().unwrap().to_str().unwrap());
-            format!("{}", path)
+            expand_tilde(text)
         } else {
             format!("{}", text)
         };
@@ -127,7 +260,7 @@ impl Feeder for DirItems {
                     let text = format!("{}", path.display());
                     text
                 })
-                .skip(_position as usize)
+                .skip(position.max(0) as usize)
                 .take(items_count)
                 .collect()
         } else {
@@ -136,136 +269,1928 @@ impl Feeder for DirItems {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::collections::HashSet;
-    use std::fs;
-    use std::iter::FromIterator;
+/// Strategy of matching query's text with items, letter case is ignored.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatchStrategy {
+    /// Item is equal to text.
+    Exact,
+    /// Item starts with text.
+    Prefix,
+    /// Item contains text.
+    Substring,
+    /// Item contains characters of text in the same order (like `fzf` does).
+    Fuzzy,
+}
 
-    fn expected(start: &str) -> HashSet<String> {
-        let found = {
-            if let Ok(v) = fs::read_dir(start) {
-                v.filter(|x| {
-                    !x.as_ref()
-                        .unwrap()
-                        .file_name()
-                        .to_str()
-                        .unwrap()
-                        .starts_with(".")
-                }).map(|x| {
-                        let p = format!("{}", x.as_ref().unwrap().path().display());
-                        p.replace("./", "")
-                    })
-                    .collect()
+const SCORE_MATCH: i64 = 16;
+const BONUS_FIRST: i64 = 12;
+const BONUS_BOUNDARY: i64 = 8;
+const BONUS_CONSECUTIVE: i64 = 6;
+const PENALTY_GAP: i64 = 1;
+
+fn lowercase_chars(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// Bonus for matching char at `idx`, prefix and word boundaries are preferred.
+fn position_bonus(chars: &[char], idx: usize) -> i64 {
+    if idx == 0 {
+        return BONUS_FIRST;
+    }
+    let (prev, current) = (chars[idx - 1], chars[idx]);
+    if (!prev.is_alphanumeric() && current.is_alphanumeric())
+        || (prev.is_lowercase() && current.is_uppercase())
+    {
+        BONUS_BOUNDARY
+    } else {
+        0
+    }
+}
+
+/// Scores matched `positions` (sorted char indices) of `chars`.
+fn score_positions(chars: &[char], positions: &[usize]) -> i64 {
+    let mut score = 0;
+    for (idx, &pos) in positions.iter().enumerate() {
+        score += SCORE_MATCH + position_bonus(chars, pos);
+        if idx > 0 {
+            let gap = (pos - positions[idx - 1] - 1) as i64;
+            score += if gap == 0 {
+                BONUS_CONSECUTIVE
             } else {
-                Vec::new()
+                -PENALTY_GAP * gap
+            };
+        }
+    }
+    score
+}
+
+/// Finds the best scored subsequence of `chars` matching `query`.
+fn fuzzy_positions(chars: &[char], lower: &[char], query: &[char]) -> Option<Vec<usize>> {
+    let (n, m) = (query.len(), chars.len());
+    // scores[i][j]: best score of query[..=i] with query[i] matched at j, prev[i][j]: j of query[i-1]
+    let mut scores: Vec<Vec<Option<i64>>> = vec![vec![None; m]; n];
+    let mut prev = vec![vec![0; m]; n];
+    for j in 0..m {
+        if lower[j] == query[0] {
+            scores[0][j] = Some(SCORE_MATCH + position_bonus(chars, j));
+        }
+    }
+    for i in 1..n {
+        // the best (score + PENALTY_GAP * k, k) for k < j - 1, gap penalty grows linearly
+        let mut gapped: Option<(i64, usize)> = None;
+        for j in 1..m {
+            if j >= 2 {
+                if let Some(score) = scores[i - 1][j - 2] {
+                    let value = score + PENALTY_GAP * (j - 2) as i64;
+                    if gapped.map(|(best, _)| value > best).unwrap_or(true) {
+                        gapped = Some((value, j - 2));
+                    }
+                }
             }
-        };
-        HashSet::<String>::from_iter(found)
+            if lower[j] != query[i] {
+                continue;
+            }
+            let consecutive = scores[i - 1][j - 1].map(|s| (s + BONUS_CONSECUTIVE, j - 1));
+            let gapped = gapped.map(|(v, k)| (v - PENALTY_GAP * (j - 1) as i64, k));
+            let best = match (consecutive, gapped) {
+                (Some(c), Some(g)) => Some(if g.0 > c.0 { g } else { c }),
+                (c, g) => c.or(g),
+            };
+            if let Some((score, k)) = best {
+                scores[i][j] = Some(score + SCORE_MATCH + position_bonus(chars, j));
+                prev[i][j] = k;
+            }
+        }
     }
+    let mut last = (0..m)
+        .filter_map(|j| scores[n - 1][j].map(|s| (s, j)))
+        .fold(None, |best: Option<(i64, usize)>, x| match best {
+            Some(b) if b.0 >= x.0 => Some(b),
+            _ => Some(x),
+        })?
+        .1;
+    let mut positions = vec![0; n];
+    for i in (0..n).rev() {
+        positions[i] = last;
+        last = prev[i][last];
+    }
+    Some(positions)
+}
 
-    #[test]
-    fn test_glob_is_added_ok() {
-        assert_eq!(add_glob(""), "*");
-        assert_eq!(add_glob("/"), "/*");
-        assert_eq!(add_glob("/home/"), "/home/*");
-        assert_eq!(add_glob("/home/user/xxx"), "/home/user/*xxx*");
-        assert_eq!(add_glob("/home/user/*xxx"), "/home/user/*xxx");
-        assert_eq!(add_glob("/home/user/xxx*"), "/home/user/xxx*");
-        assert_eq!(add_glob("**/xxx"), "**/*xxx*");
-        assert_eq!(add_glob("**/*xxx"), "**/*xxx");
-        assert_eq!(add_glob("**/xxx*"), "**/xxx*");
+/// Matches `item` with `text`, returns score and char indices of matched characters.
+fn match_item(strategy: MatchStrategy, text: &str, item: &str) -> Option<(i64, Vec<usize>)> {
+    let chars = item.chars().collect::<Vec<char>>();
+    let lower = lowercase_chars(item);
+    let query = lowercase_chars(text);
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    if query.len() > lower.len() {
+        return None;
     }
+    let span = |start: usize| (start..start + query.len()).collect::<Vec<usize>>();
+    let positions = match strategy {
+        MatchStrategy::Exact if lower == query => Some(span(0)),
+        MatchStrategy::Prefix if lower.starts_with(&query) => Some(span(0)),
+        MatchStrategy::Substring => (0..lower.len() - query.len() + 1)
+            .filter(|&start| lower[start..start + query.len()] == query[..])
+            .map(span)
+            .max_by_key(|positions| (score_positions(&chars, positions), -(positions[0] as i64))),
+        MatchStrategy::Fuzzy => fuzzy_positions(&chars, &lower, &query),
+        _ => None,
+    }?;
+    Some((score_positions(&chars, &positions), positions))
+}
 
-    #[test]
-    fn test_dir_item_works_with_current_dir() {
-        let di = DirItems::new();
-        let found = di.query("", 0, 100);
-        assert_eq!(HashSet::<String>::from_iter(found), expected("./"));
+/// Matches labels of `suggestions` with `text` and sorts them by score (the best first).
+fn rank<I>(strategy: MatchStrategy, text: &str, suggestions: I) -> Vec<Suggestion>
+where
+    I: Iterator<Item = Suggestion>,
+{
+    let mut matched = suggestions
+        .filter_map(|x| match_item(strategy, text, &x.label).map(|(s, p)| (s, x.positions(p))))
+        .collect::<Vec<(i64, Suggestion)>>();
+    // stable sort, so equally scored items keep their order
+    matched.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| a.1.label.chars().count().cmp(&b.1.label.chars().count()))
+    });
+    matched.into_iter().map(|(_, x)| x).collect()
+}
+
+/// Turns items into `Suggestion`s, implemented for `Display` items and `Suggestion`.
+pub trait ToSuggestion {
+    /// Converts item into `Suggestion`.
+    fn to_suggestion(&self) -> Suggestion;
+}
+
+impl<T: Display> ToSuggestion for T {
+    fn to_suggestion(&self) -> Suggestion {
+        Suggestion::new(format!("{}", self))
     }
+}
 
-    #[test]
-    fn test_dir_item_works_with_current_subdir() {
-        let di = DirItems::new();
-        let found = di.query("examples/", 0, 100);
-        assert_eq!(HashSet::<String>::from_iter(found), expected("./examples"));
+impl ToSuggestion for Suggestion {
+    fn to_suggestion(&self) -> Suggestion {
+        self.clone()
     }
+}
 
-    #[test]
-    fn test_dir_item_works_with_current_missing_dir() {
-        let di = DirItems::new();
-        let found = di.query("missing-dir", 0, 10);
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            expected("./missing-dir")
-        );
+fn suggest_items<T: ToSuggestion>(
+    items: &[T],
+    strategy: MatchStrategy,
+    text: &str,
+    position: isize,
+    items_count: usize,
+) -> Vec<Suggestion> {
+    let items = items.iter().map(|x| x.to_suggestion());
+    rank(strategy, text, items)
+        .into_iter()
+        .skip(position.max(0) as usize)
+        .take(items_count)
+        .collect()
+}
+
+/// Suggests `items` matched with selected `MatchStrategy`, sorted by match score.
+///
+/// Items with the same score keep their order, `Vec<T>` can be used as
+/// `Items` with `MatchStrategy::Fuzzy`. Items are `Display` values or `Suggestion`s
+/// (which labels are matched).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Items, MatchStrategy};
+/// # fn main() {
+/// let items = Items::new(vec!["build", "bench", "doc"]).strategy(MatchStrategy::Prefix);
+/// assert_eq!(items.query("B", 0, 10), vec!["build", "bench"]);
+///
+/// let items = vec!["cargo-build", "cargo-bench", "rebuild"];
+/// assert_eq!(items.query("cb", 0, 10), vec!["cargo-build", "cargo-bench"]);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Items<T> {
+    items: Vec<T>,
+    strategy: MatchStrategy,
+}
+
+impl<T: ToSuggestion> Items<T> {
+    /// Creates a new `Items` which fuzzy-matches `items`.
+    pub fn new(items: Vec<T>) -> Self {
+        Items {
+            items,
+            strategy: MatchStrategy::Fuzzy,
+        }
     }
 
-    #[test]
-    fn test_dir_item_works_with_homedir() {
-        let di = DirItems::new();
-        let found = di.query("~/", 0, 200);
-        let homedir = env::home_dir().unwrap();
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            expected(homedir.to_str().unwrap())
-        );
+    /// Sets strategy of matching items.
+    pub fn strategy(mut self, strategy: MatchStrategy) -> Self {
+        self.strategy = strategy;
+        self
     }
+}
 
-    #[test]
-    fn test_dir_item_works_with_root_dir() {
-        let di = DirItems::new();
-        let found = di.query("/root", 0, 100);
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            HashSet::<String>::from_iter(vec!["/root".to_string()])
-        );
+impl<T: ToSuggestion + 'static> Feeder for Items<T> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
     }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_items(&self.items, self.strategy, text, position, items_count)
+    }
+}
 
-    #[test]
-    fn test_dir_item_works_with_root_subdir() {
-        let di = DirItems::new();
-        let found = di.query("/root/", 0, 100);
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            HashSet::<String>::new()
-        );
+impl<T: ToSuggestion + 'static> Feeder for Vec<T> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
     }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_items(self, MatchStrategy::Fuzzy, text, position, items_count)
+    }
+}
 
-    #[test]
-    fn test_dir_item_works_with_top_missing_dir() {
-        let di = DirItems::new();
-        let found = di.query("/missing-dir", 0, 10);
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            HashSet::<String>::new()
-        );
+impl Feeder for Rc<Feeder> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        (**self).query(text, position, items_count)
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        (**self).suggest(text, position, items_count)
     }
+    fn is_updated(&self) -> bool {
+        (**self).is_updated()
+    }
+}
 
-    #[test]
-    fn test_dir_item_works_with_broken_glob() {
-        let di = DirItems::new();
-        let found = di.query("**.", 0, 10);
-        assert_eq!(
-            HashSet::<String>::from_iter(found),
-            HashSet::<String>::new()
-        );
+#[derive(Default)]
+struct AsyncState {
+    /// Number of the latest requested query, older queries are stale.
+    generation: usize,
+    /// Text of the latest query which wasn't picked up by the worker yet.
+    request: Option<String>,
+    /// Text of query being run.
+    loading: Option<String>,
+    /// Text of query which results are ready.
+    ready: Option<String>,
+    results: Vec<Suggestion>,
+    updated: bool,
+    /// Worker thread is running.
+    started: bool,
+    /// Feeder was dropped, worker should finish.
+    closed: bool,
+}
+
+type AsyncShared = Arc<(Mutex<AsyncState>, Condvar)>;
+
+/// Runs queries of other feeder in background thread, so slow feeders don't block typing.
+///
+/// Query is started after `debounce` time (if no other query came meanwhile). A single worker
+/// thread runs only the latest query, stale queries are skipped and their results dropped.
+/// Until results are ready a single "loading…" suggestion is returned. Finished query notifies
+/// Cursive through its callback sink, which is checked only when Cursive refreshes regularly
+/// (see `Cursive::set_fps`).
+///
+/// ```no_run
+/// # extern crate fui;
+/// # use fui::cursive::Cursive;
+/// # use fui::feeders::{Async, DirItems};
+/// # use fui::views::Autocomplete;
+/// # fn main() {
+/// let mut siv = Cursive::new();
+/// siv.set_fps(10);
+/// let feeder = Async::new(DirItems::new(), siv.cb_sink().clone());
+/// siv.add_layer(Autocomplete::new(feeder));
+/// # }
+/// ```
+pub struct Async<F> {
+    feeder: Arc<F>,
+    sink: Sender<Box<CbFunc>>,
+    debounce: Duration,
+    limit: usize,
+    shared: AsyncShared,
+}
+
+impl<F: Feeder + Send + Sync + 'static> Async<F> {
+    /// Creates a new `Async` running `feeder`'s queries and notifying `sink` about results.
+    pub fn new(feeder: F, sink: Sender<Box<CbFunc>>) -> Self {
+        Async {
+            feeder: Arc::new(feeder),
+            sink,
+            debounce: Duration::from_millis(150),
+            limit: 1000,
+            shared: Arc::new((Mutex::new(AsyncState::default()), Condvar::new())),
+        }
+    }
+
+    /// Sets how long query waits for next text before it's started.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Sets maximal number of results of a single query.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns results for `text` or requests a query and returns `None`.
+    fn fetch(&self, text: &str) -> Option<Vec<Suggestion>> {
+        let (ref lock, ref cvar) = *self.shared;
+        let mut state = lock.lock().unwrap();
+        if state.ready.as_ref().map(|x| x == text).unwrap_or(false) {
+            state.updated = false;
+            return Some(state.results.clone());
+        }
+        if state.loading.as_ref().map(|x| x == text).unwrap_or(false) {
+            return None;
+        }
+        state.generation += 1;
+        state.loading = Some(text.to_string());
+        // replaces request which wasn't started yet
+        state.request = Some(text.to_string());
+        if !state.started {
+            state.started = true;
+            self.spawn_worker();
+        }
+        cvar.notify_one();
+        None
+    }
+
+    /// Starts thread running requested queries one by one.
+    fn spawn_worker(&self) {
+        let feeder = Arc::clone(&self.feeder);
+        let shared = Arc::clone(&self.shared);
+        let sink = self.sink.clone();
+        let debounce = self.debounce;
+        let limit = self.limit;
+        thread::spawn(move || {
+            let (ref lock, ref cvar) = *shared;
+            let mut state = lock.lock().unwrap();
+            loop {
+                if state.closed {
+                    return;
+                }
+                let text = match state.request.take() {
+                    Some(text) => text,
+                    None => {
+                        state = cvar.wait(state).unwrap();
+                        continue;
+                    }
+                };
+                let generation = state.generation;
+                // debounce, newer request replaces this one
+                let deadline = Instant::now() + debounce;
+                while state.generation == generation && !state.closed {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break;
+                    }
+                    state = cvar.wait_timeout(state, deadline - now).unwrap().0;
+                }
+                if state.generation != generation {
+                    continue;
+                }
+                drop(state);
+                let results = feeder.suggest(&text, 0, limit);
+                state = lock.lock().unwrap();
+                if state.generation != generation {
+                    continue;
+                }
+                state.loading = None;
+                state.ready = Some(text);
+                state.results = results;
+                state.updated = true;
+                // wakes up event loop, views pick up results on relayout
+                let _ = sink.send(Box::new(|_: &mut ::cursive::Cursive| {}));
+            }
+        });
     }
 }
 
-impl<T: Display + 'static> Feeder for Vec<T> {
-    fn query(&self, text: &str, _position: isize, items_count: usize) -> Vec<String> {
-        self.iter()
-            .map(|x| format!("{}", x))
-            .filter(|x| x.to_lowercase().contains(text))
+impl<F> Drop for Async<F> {
+    fn drop(&mut self) {
+        let (ref lock, ref cvar) = *self.shared;
+        lock.lock().unwrap().closed = true;
+        cvar.notify_one();
+    }
+}
+
+impl<F: Feeder + Send + Sync + 'static> Feeder for Async<F> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.fetch(text)
+            .unwrap_or_default()
+            .into_iter()
+            .skip(position.max(0) as usize)
             .take(items_count)
+            .map(|x| x.value)
             .collect()
     }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        match self.fetch(text) {
+            Some(results) => results
+                .into_iter()
+                .skip(position.max(0) as usize)
+                .take(items_count)
+                .collect(),
+            // selecting loading row keeps typed text
+            None if position <= 0 && items_count > 0 => {
+                vec![Suggestion::new("loading…").value(text)]
+            }
+            None => Vec::new(),
+        }
+    }
+    fn is_updated(&self) -> bool {
+        self.shared.0.lock().unwrap().updated
+    }
 }
 
-impl Feeder for Rc<Feeder> {
-    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
-        (**self).query(text, position, items_count)
+/// Memoizes results of other feeder per text, so retyped text doesn't query it again.
+///
+/// Results expire after `ttl`, at most `capacity` texts are remembered (the oldest are
+/// dropped first). With `narrow` results for longer text are picked from results of its
+/// cached prefix, which is valid only for feeders returning all items matched by the
+/// strategy (like `Items`, not `DirItems`).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Cached, Feeder, MatchStrategy};
+/// # fn main() {
+/// let feeder = Cached::new(vec!["build", "bench", "doc"]).narrow(MatchStrategy::Fuzzy);
+/// assert_eq!(feeder.query("b", 0, 10), vec!["build", "bench"]);
+/// // picked from results of "b"
+/// assert_eq!(feeder.query("bu", 0, 10), vec!["build"]);
+/// # }
+/// ```
+pub struct Cached<F> {
+    feeder: F,
+    ttl: Duration,
+    capacity: usize,
+    limit: usize,
+    narrow: Option<MatchStrategy>,
+    cache: Mutex<HashMap<String, (Instant, Vec<Suggestion>)>>,
+}
+
+impl<F: Feeder> Cached<F> {
+    /// Creates a new `Cached` memoizing results of `feeder`.
+    pub fn new(feeder: F) -> Self {
+        Cached {
+            feeder,
+            ttl: Duration::from_secs(30),
+            capacity: 100,
+            limit: 1000,
+            narrow: None,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how long results are remembered.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets maximal number of remembered texts.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets maximal number of remembered results of a single text.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Narrows results of cached prefix with `strategy` instead of querying feeder.
+    pub fn narrow(mut self, strategy: MatchStrategy) -> Self {
+        self.narrow = Some(strategy);
+        self
+    }
+
+    /// Returns all results for `text` from cache or from feeder.
+    fn fetch(&self, text: &str) -> Vec<Suggestion> {
+        let mut cache = self.cache.lock().unwrap();
+        let ttl = self.ttl;
+        cache.retain(|_, &mut (created, _)| created.elapsed() < ttl);
+        if let Some((_, results)) = cache.get(text) {
+            return results.clone();
+        }
+
+        // prefix results are usable only if they weren't truncated by limit
+        let limit = self.limit;
+        let narrowed = self.narrow.and_then(|strategy| {
+            cache
+                .iter()
+                .filter(|&(k, v)| text.starts_with(k.as_str()) && v.1.len() < limit)
+                .max_by_key(|&(k, _)| k.len())
+                .map(|(_, v)| rank(strategy, text, v.1.iter().cloned()))
+        });
+        let results = narrowed.unwrap_or_else(|| self.feeder.suggest(text, 0, limit));
+
+        if cache.len() >= self.capacity {
+            let oldest = cache
+                .iter()
+                .min_by_key(|&(_, v)| v.0)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        if self.capacity > 0 {
+            cache.insert(text.to_string(), (Instant::now(), results.clone()));
+        }
+        results
+    }
+}
+
+impl<F: Feeder> Feeder for Cached<F> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        self.fetch(text)
+            .into_iter()
+            .skip(position.max(0) as usize)
+            .take(items_count)
+            .collect()
+    }
+}
+
+/// Suggests lines printed by external command.
+///
+/// When any argument contains `{}` command is run for each query (with `{}` replaced by query's
+/// text) and its lines are suggested as they are. Otherwise command is run once, its lines are
+/// remembered and matched with `MatchStrategy` (`Fuzzy` by default).
+///
+/// Command is killed when it doesn't finish before timeout (2 seconds by default). Failure is
+/// shown as a single suggestion (with category `error`) which keeps typed text, failed command
+/// is run again on the next query.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Command, Feeder};
+/// # fn main() {
+/// let branches = Command::new("printf", vec!["master\\ndevelop\\nfeature-x\\n"]);
+/// assert_eq!(branches.query("de", 0, 10), vec!["develop"]);
+///
+/// let per_query = Command::new("echo", vec!["{}-1", "{}-2"]);
+/// assert_eq!(per_query.query("v", 0, 10), vec!["v-1 v-2"]);
+/// # }
+/// ```
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    timeout: Duration,
+    strategy: MatchStrategy,
+    /// Lines of command run once, only successful output is remembered.
+    output: Mutex<Option<Vec<String>>>,
+}
+
+impl Command {
+    /// Creates a new `Command` running `program` with `args`.
+    pub fn new<IS, I>(program: IS, args: I) -> Self
+    where
+        IS: Into<String>,
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Command {
+            program: program.into(),
+            args: args.into_iter().map(|x| x.into()).collect(),
+            timeout: Duration::from_secs(2),
+            strategy: MatchStrategy::Fuzzy,
+            output: Mutex::new(None),
+        }
+    }
+
+    /// Sets how long command can run before it's killed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets strategy of matching remembered lines (not used when command is run per query).
+    pub fn strategy(mut self, strategy: MatchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    fn run(&self, text: &str) -> Result<Vec<String>, String> {
+        let args = self.args
+            .iter()
+            .map(|x| x.replace("{}", text))
+            .collect::<Vec<String>>();
+        let stdout = run_command(&self.program, &args, None, self.timeout)?;
+        Ok(stdout
+            .lines()
+            .map(|x| x.trim())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_string())
+            .collect())
+    }
+
+    fn matched(&self, text: &str) -> Result<Vec<Suggestion>, String> {
+        if self.args.iter().any(|x| x.contains("{}")) {
+            return self.run(text)
+                .map(|lines| lines.into_iter().map(Suggestion::new).collect());
+        }
+        let mut output = self.output.lock().unwrap();
+        if output.is_none() {
+            *output = Some(self.run(text)?);
+        }
+        let lines = output.as_ref().unwrap();
+        Ok(rank(
+            self.strategy,
+            text,
+            lines.iter().map(|x| Suggestion::new(x.as_str())),
+        ))
+    }
+}
+
+impl Feeder for Command {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        page_or_error(self.matched(text), text, position, items_count)
+    }
+}
+
+/// Returns page of `found` suggestions or a single suggestion describing error.
+fn page_or_error(
+    found: Result<Vec<Suggestion>, String>,
+    text: &str,
+    position: isize,
+    items_count: usize,
+) -> Vec<Suggestion> {
+    match found {
+        Ok(found) => found
+            .into_iter()
+            .skip(position.max(0) as usize)
+            .take(items_count)
+            .collect(),
+        // selecting error row keeps typed text
+        Err(ref e) if position <= 0 && items_count > 0 => {
+            vec![Suggestion::new(e.as_str()).value(text).category("error")]
+        }
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Modification time and size of file together with items read from it.
+type LoadedFile<T> = ((SystemTime, u64), Result<Vec<T>, String>);
+
+/// Items read from file, read again when file changes.
+struct WatchedFile<T = String> {
+    path: PathBuf,
+    /// Modification time and size of file when it was read and its items.
+    loaded: Mutex<Option<LoadedFile<T>>>,
+}
+
+impl<T: Clone> WatchedFile<T> {
+    fn new(path: &str) -> Self {
+        WatchedFile {
+            path: PathBuf::from(expand_tilde(path)),
+            loaded: Mutex::new(None),
+        }
+    }
+
+    /// Returns items `parse`d from file content.
+    fn items<P>(&self, parse: P) -> Result<Vec<T>, String>
+    where
+        P: Fn(&str) -> Result<Vec<T>, String>,
+    {
+        let stamp = fs::metadata(&self.path)
+            .and_then(|m| Ok((m.modified()?, m.len())))
+            .map_err(|e| format!("File {:?} can't be accessed: {}", self.path, e))?;
+        let mut loaded = self.loaded.lock().unwrap();
+        match *loaded {
+            Some((ref cached, ref items)) if *cached == stamp => return items.clone(),
+            _ => {}
+        }
+        let items = fs::read_to_string(&self.path)
+            .map_err(|e| format!("File {:?} can't be read: {}", self.path, e))
+            .and_then(|content| parse(&content));
+        *loaded = Some((stamp, items.clone()));
+        items
+    }
+}
+
+/// Suggests lines of file (empty lines are skipped), file is read again when it changes.
+///
+/// Lines are matched like `Vec<T>` items (`MatchStrategy::Fuzzy` by default).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Lines};
+/// # fn main() {
+/// let lines = Lines::from_file("./Cargo.toml");
+/// assert_eq!(lines.query("[depend", 0, 1), vec!["[dependencies]"]);
+/// # }
+/// ```
+pub struct Lines {
+    file: WatchedFile,
+    strategy: MatchStrategy,
+}
+
+impl Lines {
+    /// Creates a new `Lines` suggesting lines of file at `path`.
+    pub fn from_file(path: &str) -> Self {
+        Lines {
+            file: WatchedFile::new(path),
+            strategy: MatchStrategy::Fuzzy,
+        }
+    }
+
+    /// Sets strategy of matching lines.
+    pub fn strategy(mut self, strategy: MatchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    fn matched(&self, text: &str) -> Result<Vec<Suggestion>, String> {
+        let lines = self.file.items(|content| {
+            Ok(content
+                .lines()
+                .map(|x| x.trim())
+                .filter(|x| !x.is_empty())
+                .map(|x| x.to_string())
+                .collect())
+        })?;
+        Ok(suggest_items(&lines, self.strategy, text, 0, lines.len()))
+    }
+}
+
+impl Feeder for Lines {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        page_or_error(Ok(self.matched(text).unwrap_or_default()), text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        page_or_error(self.matched(text), text, position, items_count)
+    }
+}
+
+/// Step of `JsonPath`.
+#[derive(Clone, Debug, PartialEq)]
+enum JsonStep {
+    Key(String),
+    Index(usize),
+    All,
+}
+
+/// Parses path like `$.hosts[*].name`, `$['key'][0]` or `$.*`.
+fn parse_json_path(path: &str) -> Result<Vec<JsonStep>, String> {
+    let error = |reason: &str| format!("Invalid JSON path {:?}: {}", path, reason);
+    let chars = path.trim().chars().collect::<Vec<char>>();
+    if chars.first() != Some(&'$') {
+        return Err(error("it must start with $"));
+    }
+    let mut steps = Vec::new();
+    let mut idx = 1;
+    while idx < chars.len() {
+        match chars[idx] {
+            '.' if chars.get(idx + 1) == Some(&'*') => {
+                steps.push(JsonStep::All);
+                idx += 2;
+            }
+            '.' => {
+                let end = chars[idx + 1..]
+                    .iter()
+                    .position(|&c| c == '.' || c == '[')
+                    .map(|x| x + idx + 1)
+                    .unwrap_or(chars.len());
+                if end == idx + 1 {
+                    return Err(error("empty key"));
+                }
+                steps.push(JsonStep::Key(chars[idx + 1..end].iter().collect()));
+                idx = end;
+            }
+            '[' => {
+                let end = chars[idx..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .map(|x| x + idx)
+                    .ok_or_else(|| error("missing ]"))?;
+                let inner = chars[idx + 1..end].iter().collect::<String>();
+                let inner = inner.trim();
+                let quoted = inner.len() >= 2
+                    && (inner.starts_with('\'') && inner.ends_with('\'')
+                        || inner.starts_with('"') && inner.ends_with('"'));
+                if inner == "*" {
+                    steps.push(JsonStep::All);
+                } else if quoted {
+                    steps.push(JsonStep::Key(inner[1..inner.len() - 1].to_string()));
+                } else {
+                    let index = inner
+                        .parse::<usize>()
+                        .map_err(|_| error(&format!("unsupported selector [{}]", inner)))?;
+                    steps.push(JsonStep::Index(index));
+                }
+                idx = end + 1;
+            }
+            c => return Err(error(&format!("unexpected {:?}", c))),
+        }
+    }
+    Ok(steps)
+}
+
+/// Collects values selected by `steps`, strings are taken as they are, other values as JSON.
+fn select_json(value: &Value, steps: &[JsonStep], found: &mut Vec<String>) {
+    let (step, rest) = match steps.split_first() {
+        Some(x) => x,
+        None => {
+            match *value {
+                Value::Null => {}
+                Value::String(ref x) => found.push(x.clone()),
+                ref x => found.push(x.to_string()),
+            }
+            return;
+        }
+    };
+    match (step, value) {
+        (JsonStep::Key(key), Value::Object(map)) => {
+            if let Some(x) = map.get(key) {
+                select_json(x, rest, found);
+            }
+        }
+        (JsonStep::Index(index), Value::Array(items)) => {
+            if let Some(x) = items.get(*index) {
+                select_json(x, rest, found);
+            }
+        }
+        (JsonStep::All, Value::Array(items)) => {
+            for x in items {
+                select_json(x, rest, found);
+            }
+        }
+        (JsonStep::All, Value::Object(map)) => {
+            for x in map.values() {
+                select_json(x, rest, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Suggests values selected from JSON file by path, file is read again when it changes.
+///
+/// Supported path syntax is `$` followed by keys (`.name` or `['name']`), indexes (`[0]`)
+/// and wildcards (`.*` or `[*]`). Values are matched like `Vec<T>` items
+/// (`MatchStrategy::Fuzzy` by default).
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, JsonPath};
+/// # use std::env::temp_dir;
+/// # use std::fs;
+/// # fn main() {
+/// let path = temp_dir().join("fui-jsonpath-doctest.json");
+/// fs::write(&path, r#"{"hosts": [{"name": "web-1"}, {"name": "db-1"}]}"#).unwrap();
+///
+/// let hosts = JsonPath::new(path.to_str().unwrap(), "$.hosts[*].name");
+/// assert_eq!(hosts.query("", 0, 10).len(), 2);
+/// assert_eq!(hosts.query("db", 0, 10), vec!["db-1"]);
+/// # }
+/// ```
+pub struct JsonPath {
+    file: WatchedFile,
+    steps: Result<Vec<JsonStep>, String>,
+    strategy: MatchStrategy,
+}
+
+impl JsonPath {
+    /// Creates a new `JsonPath` suggesting values selected by `path` from JSON `file`.
+    pub fn new(file: &str, path: &str) -> Self {
+        JsonPath {
+            file: WatchedFile::new(file),
+            steps: parse_json_path(path),
+            strategy: MatchStrategy::Fuzzy,
+        }
+    }
+
+    /// Sets strategy of matching values.
+    pub fn strategy(mut self, strategy: MatchStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    fn matched(&self, text: &str) -> Result<Vec<Suggestion>, String> {
+        let steps = self.steps.clone()?;
+        let values = self.file.items(|content| {
+            let json = ::serde_json::from_str::<Value>(content)
+                .map_err(|e| format!("File {:?} isn't valid JSON: {}", self.file.path, e))?;
+            let mut found = Vec::new();
+            select_json(&json, &steps, &mut found);
+            Ok(found)
+        })?;
+        Ok(suggest_items(&values, self.strategy, text, 0, values.len()))
+    }
+}
+
+impl Feeder for JsonPath {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        page_or_error(Ok(self.matched(text).unwrap_or_default()), text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        page_or_error(self.matched(text), text, position, items_count)
+    }
+}
+
+/// Returns page of `found` system items matched fuzzily (like `Vec<T>` items).
+fn suggest_system(
+    found: Result<Vec<Suggestion>, String>,
+    text: &str,
+    position: isize,
+    items_count: usize,
+) -> Vec<Suggestion> {
+    let found = found.map(|items| rank(MatchStrategy::Fuzzy, text, items.into_iter()));
+    page_or_error(found, text, position, items_count)
+}
+
+/// Returns sorted names of entries of `dir`.
+fn dir_names(dir: &str) -> Result<Vec<String>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Dir {:?} can't be read: {}", dir, e))?;
+    let mut names = entries
+        .filter_map(|x| x.ok())
+        .map(|x| x.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<String>>();
+    names.sort();
+    Ok(names)
+}
+
+/// Suggests names of environment variables of current process.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{EnvVars, Feeder};
+/// # fn main() {
+/// assert!(EnvVars.query("PATH", 0, 10).contains(&"PATH".to_string()));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EnvVars;
+
+impl Feeder for EnvVars {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        let mut names = env::vars_os()
+            .map(|(k, _)| k.to_string_lossy().into_owned())
+            .collect::<Vec<String>>();
+        names.sort();
+        let found = names.into_iter().map(Suggestion::new).collect();
+        suggest_system(Ok(found), text, position, items_count)
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Suggests executables found in dirs of `$PATH` (described with their dir).
+///
+/// Dirs are scanned once (on the first query), executable shadowed by the same name
+/// in earlier dir isn't suggested.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Executables, Feeder};
+/// # fn main() {
+/// assert!(Executables::new().query("sh", 0, 100).contains(&"sh".to_string()));
+/// # }
+/// ```
+pub struct Executables {
+    found: Mutex<Option<Vec<Suggestion>>>,
+}
+
+impl Executables {
+    /// Creates a new `Executables`.
+    pub fn new() -> Self {
+        Executables {
+            found: Mutex::new(None),
+        }
+    }
+
+    fn scan() -> Vec<Suggestion> {
+        let paths = env::var_os("PATH").unwrap_or_default();
+        let mut seen = ::std::collections::HashSet::new();
+        let mut found = Vec::new();
+        for dir in env::split_paths(&paths) {
+            let mut names = match dir_names(&dir.to_string_lossy()) {
+                Ok(names) => names,
+                Err(_) => continue,
+            };
+            names.retain(|x| !seen.contains(x) && is_executable(&dir.join(x)));
+            for name in names {
+                seen.insert(name.clone());
+                found.push(Suggestion::new(name).description(dir.to_string_lossy()));
+            }
+        }
+        found.sort_by(|a, b| a.label.cmp(&b.label));
+        found
+    }
+}
+
+impl Default for Executables {
+    fn default() -> Self {
+        Executables::new()
+    }
+}
+
+impl Feeder for Executables {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        let mut found = self.found.lock().unwrap();
+        if found.is_none() {
+            *found = Some(Executables::scan());
+        }
+        let found = found.clone().unwrap_or_default();
+        suggest_system(Ok(found), text, position, items_count)
+    }
+}
+
+/// Parses `/etc/passwd` like content into user names described with their full names.
+fn parse_passwd(content: &str) -> Result<Vec<Suggestion>, String> {
+    Ok(content
+        .lines()
+        .filter(|x| !x.trim().is_empty() && !x.starts_with('#'))
+        .map(|line| {
+            let fields = line.split(':').collect::<Vec<&str>>();
+            let full_name = fields.get(4).and_then(|x| x.split(',').next()).unwrap_or("");
+            Suggestion::new(fields[0]).description(full_name)
+        })
+        .collect())
+}
+
+/// Parses `/etc/group` like content into group names.
+fn parse_group(content: &str) -> Result<Vec<Suggestion>, String> {
+    Ok(content
+        .lines()
+        .filter(|x| !x.trim().is_empty() && !x.starts_with('#'))
+        .map(|line| Suggestion::new(line.split(':').next().unwrap_or("")))
+        .collect())
+}
+
+/// Suggests local users from `/etc/passwd` (described with their full names).
+///
+/// File is read again when it changes.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Users};
+/// # fn main() {
+/// assert_eq!(Users::new().query("root", 0, 1), vec!["root"]);
+/// # }
+/// ```
+pub struct Users {
+    file: WatchedFile<Suggestion>,
+}
+
+impl Users {
+    /// Creates a new `Users`.
+    pub fn new() -> Self {
+        Users {
+            file: WatchedFile::new("/etc/passwd"),
+        }
+    }
+}
+
+impl Default for Users {
+    fn default() -> Self {
+        Users::new()
+    }
+}
+
+impl Feeder for Users {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        let found = Ok(self.file.items(parse_passwd).unwrap_or_default());
+        suggest_system(found, text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_system(self.file.items(parse_passwd), text, position, items_count)
+    }
+}
+
+/// Suggests local groups from `/etc/group`.
+///
+/// File is read again when it changes.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Groups};
+/// # fn main() {
+/// assert_eq!(Groups::new().query("root", 0, 1), vec!["root"]);
+/// # }
+/// ```
+pub struct Groups {
+    file: WatchedFile<Suggestion>,
+}
+
+impl Groups {
+    /// Creates a new `Groups`.
+    pub fn new() -> Self {
+        Groups {
+            file: WatchedFile::new("/etc/group"),
+        }
+    }
+}
+
+impl Default for Groups {
+    fn default() -> Self {
+        Groups::new()
+    }
+}
+
+impl Feeder for Groups {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        let found = Ok(self.file.items(parse_group).unwrap_or_default());
+        suggest_system(found, text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_system(self.file.items(parse_group), text, position, items_count)
+    }
+}
+
+/// Returns running processes read from `/proc` as "pid command" with pid value.
+fn process_items() -> Result<Vec<Suggestion>, String> {
+    let mut pids = dir_names("/proc")?
+        .into_iter()
+        .filter_map(|x| x.parse::<u32>().ok())
+        .collect::<Vec<u32>>();
+    pids.sort();
+    Ok(pids.into_iter()
+        .filter_map(|pid| {
+            // process can finish meanwhile
+            let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).ok()?;
+            let cmdline = String::from_utf8_lossy(&cmdline).replace('\0', " ");
+            let command = match cmdline.trim() {
+                "" => {
+                    let comm = fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
+                    format!("[{}]", comm.trim())
+                }
+                command => command.to_string(),
+            };
+            Some(Suggestion::new(format!("{} {}", pid, command)).value(pid.to_string()))
+        })
+        .collect())
+}
+
+/// Suggests running processes (pid and command line, pid is submitted) read from `/proc`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Processes};
+/// # use std::process;
+/// # fn main() {
+/// let pid = process::id().to_string();
+/// assert!(Processes.query(&pid, 0, 100).contains(&pid));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Processes;
+
+impl Feeder for Processes {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        let found = Ok(process_items().unwrap_or_default());
+        suggest_system(found, text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_system(process_items(), text, position, items_count)
+    }
+}
+
+/// Returns names of network interfaces read from `/sys/class/net`.
+fn net_interface_items() -> Result<Vec<Suggestion>, String> {
+    Ok(dir_names("/sys/class/net")?
+        .into_iter()
+        .map(Suggestion::new)
+        .collect())
+}
+
+/// Suggests network interfaces read from `/sys/class/net`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, NetInterfaces};
+/// # fn main() {
+/// assert_eq!(NetInterfaces.query("lo", 0, 1), vec!["lo"]);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NetInterfaces;
+
+impl Feeder for NetInterfaces {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        let found = Ok(net_interface_items().unwrap_or_default());
+        suggest_system(found, text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        suggest_system(net_interface_items(), text, position, items_count)
+    }
+}
+
+/// Suggests items of the first feeder followed by items of the second one.
+///
+/// Created with `Feeder::chain`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::Feeder;
+/// # fn main() {
+/// let feeder = vec!["build", "bench"].chain(vec!["bundle"]);
+/// assert_eq!(feeder.query("b", 0, 10), vec!["build", "bench", "bundle"]);
+/// assert_eq!(feeder.query("b", 1, 2), vec!["bench", "bundle"]);
+/// # }
+/// ```
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+/// Returns page starting at `position` of items of `first` followed by items of `second`.
+fn chain_page<T, A, B>(first: A, second: B, position: isize, items_count: usize) -> Vec<T>
+where
+    A: Fn(isize, usize) -> Vec<T>,
+    B: Fn(isize, usize) -> Vec<T>,
+{
+    let position = position.max(0) as usize;
+    // items before position are needed to know where the second feeder starts
+    let first_items = first(0, position + items_count);
+    let first_count = first_items.len();
+    let mut found = first_items.into_iter().skip(position).collect::<Vec<T>>();
+    if found.len() < items_count {
+        let skipped = position.saturating_sub(first_count);
+        found.extend(second(skipped as isize, items_count - found.len()));
+    }
+    found
+}
+
+impl<A: Feeder, B: Feeder> Feeder for Chain<A, B> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        chain_page(
+            |p, c| self.first.query(text, p, c),
+            |p, c| self.second.query(text, p, c),
+            position,
+            items_count,
+        )
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        chain_page(
+            |p, c| self.first.suggest(text, p, c),
+            |p, c| self.second.suggest(text, p, c),
+            position,
+            items_count,
+        )
+    }
+    fn is_updated(&self) -> bool {
+        self.first.is_updated() || self.second.is_updated()
+    }
+}
+
+/// Transforms suggestions of other feeder with closure.
+///
+/// Created with `Feeder::map`, `query` returns values of suggestions created from values
+/// of feeder's `query`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::{Feeder, Suggestion};
+/// # fn main() {
+/// let feeder = vec!["main", "develop"].map(|s| Suggestion::new(s.label).category("branch"));
+/// assert_eq!(feeder.suggest("dev", 0, 10)[0].category, "branch");
+/// # }
+/// ```
+pub struct Map<F, M> {
+    feeder: F,
+    map: M,
+}
+
+impl<F, M> Feeder for Map<F, M>
+where
+    F: Feeder,
+    M: Fn(Suggestion) -> Suggestion + 'static,
+{
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        self.suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| x.value)
+            .collect()
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        self.feeder
+            .suggest(text, position, items_count)
+            .into_iter()
+            .map(|x| (self.map)(x))
+            .collect()
+    }
+    fn is_updated(&self) -> bool {
+        self.feeder.is_updated()
+    }
+}
+
+/// Maximal number of items of inner feeder scanned by `Filter` and `Dedupe`.
+const SCAN_LIMIT: usize = 10000;
+
+/// Returns page starting at `position` of `query`'s items which are `kept`.
+///
+/// Items are queried once from the first one (at most `SCAN_LIMIT` of them), so `keep` sees
+/// all preceding items.
+fn kept_page<T, Q, K>(query: Q, mut keep: K, position: isize, items_count: usize) -> Vec<T>
+where
+    Q: Fn(isize, usize) -> Vec<T>,
+    K: FnMut(&T) -> bool,
+{
+    if items_count == 0 {
+        return Vec::new();
+    }
+    query(0, SCAN_LIMIT)
+        .into_iter()
+        .filter(|x| keep(x))
+        .skip(position.max(0) as usize)
+        .take(items_count)
+        .collect()
+}
+
+/// Suggests items of other feeder which values satisfy predicate.
+///
+/// Created with `Feeder::filter`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::Feeder;
+/// # fn main() {
+/// let feeder = vec!["a.tar", "b.zip", "c.tar"].filter(|s| s.ends_with(".tar"));
+/// assert_eq!(feeder.query("", 0, 10), vec!["a.tar", "c.tar"]);
+/// # }
+/// ```
+pub struct Filter<F, P> {
+    feeder: F,
+    predicate: P,
+}
+
+impl<F, P> Feeder for Filter<F, P>
+where
+    F: Feeder,
+    P: Fn(&str) -> bool + 'static,
+{
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        kept_page(
+            |p, c| self.feeder.query(text, p, c),
+            |x| (self.predicate)(x),
+            position,
+            items_count,
+        )
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        kept_page(
+            |p, c| self.feeder.suggest(text, p, c),
+            |x| (self.predicate)(&x.value),
+            position,
+            items_count,
+        )
+    }
+    fn is_updated(&self) -> bool {
+        self.feeder.is_updated()
+    }
+}
+
+/// Suggests only the first of other feeder's items with the same value.
+///
+/// Created with `Feeder::dedupe`.
+///
+/// ```
+/// # extern crate fui;
+/// # use fui::feeders::Feeder;
+/// # fn main() {
+/// let feeder = vec!["build", "bench"].chain(vec!["build"]).dedupe();
+/// assert_eq!(feeder.query("b", 0, 10), vec!["build", "bench"]);
+/// # }
+/// ```
+pub struct Dedupe<F> {
+    feeder: F,
+}
+
+impl<F: Feeder> Feeder for Dedupe<F> {
+    fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+        let mut seen = ::std::collections::HashSet::new();
+        kept_page(
+            |p, c| self.feeder.query(text, p, c),
+            |x| seen.insert(x.clone()),
+            position,
+            items_count,
+        )
+    }
+    fn suggest(&self, text: &str, position: isize, items_count: usize) -> Vec<Suggestion> {
+        let mut seen = ::std::collections::HashSet::new();
+        kept_page(
+            |p, c| self.feeder.suggest(text, p, c),
+            |x| seen.insert(x.value.clone()),
+            position,
+            items_count,
+        )
+    }
+    fn is_updated(&self) -> bool {
+        self.feeder.is_updated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::env;
+    use std::fs;
+    use std::iter::FromIterator;
+    use std::process;
+
+    fn expected(start: &str) -> HashSet<String> {
+        let found = {
+            if let Ok(v) = fs::read_dir(start) {
+                v.filter(|x| {
+                    !x.as_ref()
+                        .unwrap()
+                        .file_name()
+                        .to_str()
+                        .unwrap()
+                        .starts_with(".")
+                }).map(|x| {
+                        let p = format!("{}", x.as_ref().unwrap().path().display());
+                        p.replace("./", "")
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        };
+        HashSet::<String>::from_iter(found)
+    }
+
+    #[test]
+    fn test_glob_is_added_ok() {
+        assert_eq!(add_glob(""), "*");
+        assert_eq!(add_glob("/"), "/*");
+        assert_eq!(add_glob("/home/"), "/home/*");
+        assert_eq!(add_glob("/home/user/xxx"), "/home/user/*xxx*");
+        assert_eq!(add_glob("/home/user/*xxx"), "/home/user/*xxx");
+        assert_eq!(add_glob("/home/user/xxx*"), "/home/user/xxx*");
+        assert_eq!(add_glob("**/xxx"), "**/*xxx*");
+        assert_eq!(add_glob("**/*xxx"), "**/*xxx");
+        assert_eq!(add_glob("**/xxx*"), "**/xxx*");
+    }
+
+    #[test]
+    fn test_dir_item_works_with_current_dir() {
+        let di = DirItems::new();
+        let found = di.query("", 0, 100);
+        assert_eq!(HashSet::<String>::from_iter(found), expected("./"));
+    }
+
+    #[test]
+    fn test_dir_item_works_with_current_subdir() {
+        let di = DirItems::new();
+        let found = di.query("examples/", 0, 100);
+        assert_eq!(HashSet::<String>::from_iter(found), expected("./examples"));
+    }
+
+    #[test]
+    fn test_dir_item_works_with_current_missing_dir() {
+        let di = DirItems::new();
+        let found = di.query("missing-dir", 0, 10);
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            expected("./missing-dir")
+        );
+    }
+
+    #[test]
+    fn test_dir_item_works_with_homedir() {
+        let di = DirItems::new();
+        let found = di.query("~/", 0, 200);
+        let homedir = env::home_dir().unwrap();
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            expected(homedir.to_str().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_dir_item_works_with_root_dir() {
+        let di = DirItems::new();
+        let found = di.query("/root", 0, 100);
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            HashSet::<String>::from_iter(vec!["/root".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_dir_item_works_with_root_subdir() {
+        let di = DirItems::new();
+        let found = di.query("/root/", 0, 100);
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            HashSet::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_dir_item_works_with_top_missing_dir() {
+        let di = DirItems::new();
+        let found = di.query("/missing-dir", 0, 10);
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            HashSet::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_vec_matches_ignoring_case() {
+        let items = vec!["Option1", "option2", "other"];
+        assert_eq!(items.query("OPT", 0, 10), vec!["Option1", "option2"]);
+    }
+
+    #[test]
+    fn test_vec_keeps_order_for_empty_text() {
+        let items = vec!["c", "b", "a"];
+        assert_eq!(items.query("", 0, 2), vec!["c", "b"]);
+    }
+
+    #[test]
+    fn test_fuzzy_ranks_prefix_and_word_boundaries_first() {
+        let items = vec!["xfoobar", "fxoxoxbar", "foo-bar", "foobar"];
+        assert_eq!(
+            items.query("fb", 0, 10),
+            vec!["foo-bar", "foobar", "fxoxoxbar", "xfoobar"]
+        );
+        assert_eq!(
+            items.query("foob", 0, 10),
+            vec!["foo-bar", "foobar", "xfoobar", "fxoxoxbar"]
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_positions_prefer_boundaries() {
+        assert_eq!(
+            match_item(MatchStrategy::Fuzzy, "cb", "cargo-build").map(|x| x.1),
+            Some(vec![0, 6])
+        );
+        assert_eq!(
+            match_item(MatchStrategy::Fuzzy, "ab", "aXbAB").map(|x| x.1),
+            Some(vec![3, 4])
+        );
+        assert_eq!(match_item(MatchStrategy::Fuzzy, "ba", "ab"), None);
+    }
+
+    #[test]
+    fn test_vec_honors_position() {
+        let items = vec!["a1", "a2", "a3", "b1"];
+        assert_eq!(items.query("a", 1, 10), vec!["a2", "a3"]);
+        assert_eq!(items.query("a", 2, 1), vec!["a3"]);
+        assert_eq!(items.query("a", 5, 1), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_vec_suggests_matched_positions() {
+        let items = vec!["cargo-build", "cargo-bench"];
+        assert_eq!(
+            items.suggest("cbu", 0, 10),
+            vec![Suggestion::new("cargo-build").positions(vec![0, 6, 7])]
+        );
+    }
+
+    #[test]
+    fn test_strategies() {
+        let items = vec!["test", "testing", "attest"];
+        let query = |strategy| Items::new(items.clone()).strategy(strategy).query("TEST", 0, 10);
+        assert_eq!(query(MatchStrategy::Exact), vec!["test"]);
+        assert_eq!(query(MatchStrategy::Prefix), vec!["test", "testing"]);
+        assert_eq!(query(MatchStrategy::Substring), vec!["test", "testing", "attest"]);
+        assert_eq!(query(MatchStrategy::Fuzzy), vec!["test", "testing", "attest"]);
+    }
+
+    #[test]
+    fn test_dir_item_works_with_broken_glob() {
+        let di = DirItems::new();
+        let found = di.query("**.", 0, 10);
+        assert_eq!(
+            HashSet::<String>::from_iter(found),
+            HashSet::<String>::new()
+        );
+    }
+
+    #[test]
+    fn test_async_shows_loading_until_results_are_ready() {
+        let (sink, _receiver) = ::std::sync::mpsc::channel();
+        let feeder = Async::new(vec!["option1", "option2"], sink).debounce(Duration::from_millis(0));
+        let labels = |f: &Async<Vec<&'static str>>| {
+            f.suggest("opt", 0, 10)
+                .into_iter()
+                .map(|x| x.label)
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(labels(&feeder), vec!["loading…"]);
+        assert_eq!(feeder.query("opt", 0, 10), Vec::<String>::new());
+        for _ in 0..100 {
+            if feeder.is_updated() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(feeder.is_updated());
+        assert_eq!(labels(&feeder), vec!["option1", "option2"]);
+        assert!(!feeder.is_updated());
+    }
+
+    struct Recording(Arc<Mutex<Vec<String>>>);
+    impl Feeder for Recording {
+        fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+            self.0.lock().unwrap().push(text.to_string());
+            vec!["alpha", "beta", "alphabet"].query(text, position, items_count)
+        }
+    }
+
+    #[test]
+    fn test_async_runs_only_the_latest_query() {
+        let (sink, _receiver) = ::std::sync::mpsc::channel();
+        let queried = Arc::new(Mutex::new(Vec::new()));
+        let feeder = Async::new(Recording(Arc::clone(&queried)), sink)
+            .debounce(Duration::from_millis(100));
+
+        for text in &["a", "al", "alp"] {
+            assert_eq!(feeder.query(text, 0, 10), Vec::<String>::new());
+        }
+        for _ in 0..100 {
+            if feeder.is_updated() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(feeder.query("alp", 0, 10), vec!["alpha", "alphabet"]);
+        assert_eq!(*queried.lock().unwrap(), vec!["alp"]);
+    }
+
+    struct Counting(Rc<::std::cell::Cell<usize>>);
+    impl Feeder for Counting {
+        fn query(&self, text: &str, position: isize, items_count: usize) -> Vec<String> {
+            self.0.set(self.0.get() + 1);
+            vec!["alpha", "beta", "alphabet"].query(text, position, items_count)
+        }
+    }
+
+    #[test]
+    fn test_cached_queries_feeder_once_per_text() {
+        let count = Rc::new(::std::cell::Cell::new(0));
+        let feeder = Cached::new(Counting(Rc::clone(&count)));
+        assert_eq!(feeder.query("al", 0, 10), vec!["alpha", "alphabet"]);
+        assert_eq!(feeder.query("al", 0, 1), vec!["alpha"]);
+        assert_eq!(feeder.query("al", 1, 10), vec!["alphabet"]);
+        assert_eq!(count.get(), 1);
+        feeder.query("alp", 0, 10);
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn test_cached_narrows_prefix_results() {
+        let count = Rc::new(::std::cell::Cell::new(0));
+        let feeder = Cached::new(Counting(Rc::clone(&count))).narrow(MatchStrategy::Fuzzy);
+        feeder.query("a", 0, 10);
+        assert_eq!(feeder.query("abt", 0, 10), vec!["alphabet"]);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn test_cached_respects_ttl_and_capacity() {
+        let count = Rc::new(::std::cell::Cell::new(0));
+        let feeder = Cached::new(Counting(Rc::clone(&count))).capacity(1);
+        feeder.query("a", 0, 10);
+        feeder.query("b", 0, 10);
+        feeder.query("a", 0, 10);
+        assert_eq!(count.get(), 3);
+
+        let feeder = Cached::new(Counting(Rc::clone(&count))).ttl(Duration::from_millis(0));
+        feeder.query("a", 0, 10);
+        feeder.query("a", 0, 10);
+        assert_eq!(count.get(), 5);
+    }
+
+    #[test]
+    fn test_command_reports_errors() {
+        let error = |f: Command| {
+            let found = f.suggest("text", 0, 10);
+            assert_eq!(f.query("text", 0, 10), vec!["text"]);
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].value, "text");
+            found[0].label.clone()
+        };
+
+        let f = Command::new("fui-missing-program", Vec::<String>::new());
+        assert!(error(f).starts_with("Command \"fui-missing-program\" can't be run"));
+
+        let f = Command::new("sh", vec!["-c", "echo failed >&2; exit 1"]);
+        assert_eq!(error(f), "failed");
+
+        let f = Command::new("sh", vec!["-c", "sleep 1; echo {}"]).timeout(Duration::from_millis(100));
+        assert_eq!(error(f), "Command timed out after 100ms");
+    }
+
+    #[test]
+    fn test_command_runs_again_after_failure() {
+        let flag = env::temp_dir().join(format!("fui-command-retry-{}", process::id()));
+        let _ = fs::remove_file(&flag);
+        let script = format!("cat {:?} || exit 1", flag);
+        let f = Command::new("sh", vec!["-c", script.as_str()]);
+        assert_eq!(f.suggest("", 0, 10)[0].category, "error");
+
+        fs::write(&flag, "ready\n").unwrap();
+        assert_eq!(f.query("", 0, 10), vec!["ready"]);
+        // successful output is remembered
+        fs::remove_file(&flag).unwrap();
+        assert_eq!(f.query("", 0, 10), vec!["ready"]);
+    }
+
+    #[test]
+    fn test_json_path_parses_supported_syntax() {
+        assert_eq!(
+            parse_json_path("$.hosts[*].name"),
+            Ok(vec![
+                JsonStep::Key("hosts".to_string()),
+                JsonStep::All,
+                JsonStep::Key("name".to_string()),
+            ])
+        );
+        assert_eq!(
+            parse_json_path("$['a.b'][2].*"),
+            Ok(vec![
+                JsonStep::Key("a.b".to_string()),
+                JsonStep::Index(2),
+                JsonStep::All,
+            ])
+        );
+        assert_eq!(parse_json_path("$"), Ok(vec![]));
+        assert!(parse_json_path("hosts").is_err());
+        assert!(parse_json_path("$..name").is_err());
+        assert!(parse_json_path("$[?(@.x)]").is_err());
+    }
+
+    #[test]
+    fn test_lines_reloads_changed_file() {
+        let path = env::temp_dir().join("fui-test-lines-reload.txt");
+        fs::write(&path, "alpha\n\nbeta\n").unwrap();
+        let lines = Lines::from_file(path.to_str().unwrap());
+        assert_eq!(lines.query("a", 0, 10), vec!["alpha", "beta"]);
+
+        fs::write(&path, "alpha\nbeta\ngamma\n").unwrap();
+        assert_eq!(lines.query("a", 0, 10), vec!["alpha", "beta", "gamma"]);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(lines.query("", 0, 10), Vec::<String>::new());
+        assert_eq!(lines.suggest("", 0, 10)[0].category, "error");
+    }
+
+    #[test]
+    fn test_parse_passwd_and_group() {
+        let passwd = "# comment\nroot:x:0:0:root:/root:/bin/bash\n\n\
+                      jdoe:x:1000:1000:John Doe,,,:/home/jdoe:/bin/sh\nnobody:x:65534:65534::/:\n";
+        let users = parse_passwd(passwd).unwrap();
+        assert_eq!(
+            users.iter().map(|x| x.label.as_str()).collect::<Vec<&str>>(),
+            vec!["root", "jdoe", "nobody"]
+        );
+        assert_eq!(users[1].description, "John Doe");
+        assert_eq!(users[2].description, "");
+
+        let groups = parse_group("root:x:0:\nwheel:x:10:jdoe\n").unwrap();
+        assert_eq!(
+            groups.iter().map(|x| x.label.as_str()).collect::<Vec<&str>>(),
+            vec!["root", "wheel"]
+        );
+    }
+
+    #[test]
+    fn test_env_vars_lists_variables() {
+        env::set_var("FUI_TEST_ENV_VARS_FEEDER", "1");
+        assert_eq!(EnvVars.query("FUI_TEST_ENV_VARS", 0, 10), vec!["FUI_TEST_ENV_VARS_FEEDER"]);
+    }
+
+    #[test]
+    fn test_chain_pages_across_feeders() {
+        let feeder = vec!["a1", "a2", "a3"].chain(vec!["b1", "b2"]);
+        assert_eq!(feeder.query("", 0, 2), vec!["a1", "a2"]);
+        assert_eq!(feeder.query("", 2, 2), vec!["a3", "b1"]);
+        assert_eq!(feeder.query("", 4, 2), vec!["b2"]);
+        assert_eq!(feeder.query("", 6, 2), Vec::<String>::new());
+        assert_eq!(feeder.query("b", 0, 10), vec!["b1", "b2"]);
+    }
+
+    #[test]
+    fn test_filter_and_dedupe_page_kept_items() {
+        let items = (0..250).map(|x| format!("item{}", x % 125)).collect::<Vec<String>>();
+        let feeder = Items::new(items)
+            .strategy(MatchStrategy::Prefix)
+            .filter(|s| s.ends_with('0'))
+            .dedupe();
+        assert_eq!(feeder.query("item", 0, 3), vec!["item0", "item10", "item20"]);
+        assert_eq!(feeder.query("item", 11, 10), vec!["item110", "item120"]);
+        assert_eq!(
+            feeder
+                .suggest("item", 12, 10)
+                .into_iter()
+                .map(|x| x.value)
+                .collect::<Vec<String>>(),
+            vec!["item120"]
+        );
+    }
+
+    /// Ignores position, returns the same items for every page.
+    struct Repeating(Arc<Mutex<usize>>);
+    impl Feeder for Repeating {
+        fn query(&self, _: &str, _: isize, items_count: usize) -> Vec<String> {
+            *self.0.lock().unwrap() += 1;
+            vec!["same".to_string(); items_count.min(150)]
+        }
+    }
+
+    #[test]
+    fn test_dedupe_queries_feeder_ignoring_position_once() {
+        let calls = Arc::new(Mutex::new(0));
+        let feeder = Repeating(Arc::clone(&calls)).dedupe();
+        assert_eq!(feeder.query("", 0, 10), vec!["same"]);
+        assert_eq!(feeder.query("", 1, 10), Vec::<String>::new());
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_map_query_and_suggest_return_the_same_values() {
+        let feeder = vec![Suggestion::new("#1 Fix").value("1")]
+            .map(|s| {
+                let value = format!("v:{}", s.label);
+                s.value(value)
+            });
+        let suggested = feeder
+            .suggest("", 0, 10)
+            .into_iter()
+            .map(|x| x.value)
+            .collect::<Vec<String>>();
+        assert_eq!(suggested, vec!["v:#1 Fix"]);
+        assert_eq!(feeder.query("", 0, 10), suggested);
+    }
+
+    #[test]
+    fn test_map_transforms_suggestions() {
+        let feeder = vec!["main"].map(|s| s.value("refs/heads/main").description("branch"));
+        assert_eq!(feeder.query("ma", 0, 10), vec!["refs/heads/main"]);
+        let found = feeder.suggest("ma", 0, 10);
+        assert_eq!(found[0].label, "main");
+        assert_eq!(found[0].description, "branch");
     }
 }
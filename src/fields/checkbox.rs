@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use cursive::utils::markup::StyledString;
 use cursive::view::AnyView;
 use cursive::views;
 use serde_json::value::Value;
@@ -44,8 +45,8 @@ impl fields::WidgetManager for CheckboxManager {
         let value = checkbox.is_checked();
         format!("{}", value)
     }
-    fn set_error(&self, _view: &mut AnyView, _error: &str) {
-        // no operation, checkbox is always valid
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        fields::set_layout_message(view, StyledString::plain(error));
     }
     fn build_value_view(&self, value: &str) -> Box<AnyView> {
         let value = FromStr::from_str(value).unwrap();
@@ -67,11 +68,23 @@ impl fields::FormField for fields::Field<CheckboxManager, bool> {
     fn get_label(&self) -> &str {
         &self.label
     }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
     fn validate(&self, data: &str) -> Result<Value, String> {
-        let value = FromStr::from_str(data)
+        let data = self.normalized(data);
+        let value = FromStr::from_str(&data)
             .map(|v| Value::Bool(v))
-            .map_err(|_| "Value can't be converterd to bool".to_string());
-        value
+            .map_err(|_| "Value can't be converterd to bool".to_string())?;
+        for v in &self.validators {
+            if let Some(e) = v.validate(&data) {
+                return Err(e);
+            }
+        }
+        Ok(value)
     }
 }
 
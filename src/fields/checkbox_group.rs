@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use cursive::view::AnyView;
+use cursive::views::LinearLayout;
+use serde_json;
+use serde_json::value::Value;
+
+use fields::readonly::set_error;
+use fields::{label_with_help_layout, Field, FormField, WidgetManager};
+use views;
+
+/// Convienient wrapper around `Field<CheckboxGroupManager, Vec<String>>`.
+pub struct CheckboxGroup;
+
+impl CheckboxGroup {
+    /// Creates a new `Field<CheckboxGroupManager, Vec<String>>` with checkbox for each option.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>, T: Display>(
+        label: IS,
+        options: Vec<T>,
+    ) -> Field<CheckboxGroupManager, Vec<String>> {
+        let mngr = CheckboxGroupManager {
+            options: options.iter().map(|x| format!("{}", x)).collect(),
+            min_selected: 0,
+            max_selected: None,
+        };
+        Field::new(label, mngr, Vec::new())
+    }
+}
+
+#[derive(Clone)]
+pub struct CheckboxGroupManager {
+    options: Vec<String>,
+    min_selected: usize,
+    max_selected: Option<usize>,
+}
+
+impl WidgetManager for CheckboxGroupManager {
+    fn build_value_view(&self, initial: &str) -> Box<AnyView> {
+        let selected: Vec<String> = serde_json::from_str(initial).unwrap_or_default();
+        let mut widget = views::CheckboxGroup::new(self.options.clone());
+        widget.select_items(&selected);
+        Box::new(widget)
+    }
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let group = (**boxed_field)
+            .as_any()
+            .downcast_ref::<views::CheckboxGroup>()
+            .unwrap();
+        serde_json::to_string(&group.get_selected_items()).unwrap()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        set_error(view, error)
+    }
+}
+
+impl FormField for Field<CheckboxGroupManager, Vec<String>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        let items: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        self.check_items_warnings(&items)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        let items: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        self.check_items_pending(&items)
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let items: Vec<String> = serde_json::from_str(data)
+            .map_err(|_| "Value can't be converted to list".to_string())?;
+        let items = items
+            .iter()
+            .map(|x| self.normalized(x))
+            .collect::<Vec<String>>();
+        if items.len() < self.widget_manager.min_selected {
+            return Err(format!(
+                "At least {} option(s) must be selected",
+                self.widget_manager.min_selected
+            ));
+        }
+        if let Some(max) = self.widget_manager.max_selected {
+            if items.len() > max {
+                return Err(format!("At most {} option(s) can be selected", max));
+            }
+        }
+        for v in &self.validators {
+            if let Some(e) = v.validate_items(&items) {
+                return Err(e);
+            }
+        }
+        Ok(Value::Array(items.into_iter().map(Value::String).collect()))
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        let initial = serde_json::to_string(&self.initial).unwrap();
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &initial)
+    }
+}
+
+impl Field<CheckboxGroupManager, Vec<String>> {
+    /// Sets minimal number of selected options.
+    pub fn min_selected(mut self, min_selected: usize) -> Self {
+        self.widget_manager.min_selected = min_selected;
+        self
+    }
+    /// Sets maximal number of selected options.
+    pub fn max_selected(mut self, max_selected: usize) -> Self {
+        self.widget_manager.max_selected = Some(max_selected);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(items: &[&str]) -> String {
+        serde_json::to_string(items).unwrap()
+    }
+
+    #[test]
+    fn test_checkbox_group_checks_number_of_selected_options() {
+        let field = CheckboxGroup::new("enable", vec!["tests", "docs", "benches"])
+            .min_selected(1)
+            .max_selected(2);
+        assert_eq!(
+            field.validate(&selection(&[])),
+            Err("At least 1 option(s) must be selected".to_string())
+        );
+        assert_eq!(
+            field.validate(&selection(&["tests", "docs", "benches"])),
+            Err("At most 2 option(s) can be selected".to_string())
+        );
+        assert_eq!(
+            field.validate(&selection(&["tests", "docs"])),
+            Ok(serde_json::from_str(r#"["tests", "docs"]"#).unwrap())
+        );
+        assert!(field.validate("tests").is_err());
+    }
+
+    #[test]
+    fn test_checkbox_group_submits_selected_options() {
+        let field = CheckboxGroup::new("enable", vec!["tests", "docs"]).initial(vec!["docs"]);
+        let widget = field.build_widget();
+        assert_eq!(field.get_widget_manager().get_value(&widget), selection(&["docs"]));
+    }
+}
@@ -0,0 +1,95 @@
+use std::rc::Rc;
+
+use cursive::view::AnyView;
+use cursive::views;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use fields::readonly::{get_text, set_error};
+
+/// Convienient wrapper around `Field<ComputedManager, String>`.
+///
+/// Field shows value computed from other fields' current values (like final archive name)
+/// which is updated while user edits the form.
+///
+/// `compute` gets object with current values (not validated, as strings) of all form's fields.
+pub struct Computed;
+
+impl Computed {
+    /// Creates a new `Field<ComputedManager, String>` showing result of `compute`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS, F>(label: IS, compute: F) -> fields::Field<ComputedManager, String>
+    where
+        IS: Into<String>,
+        F: Fn(&Value) -> String + 'static,
+    {
+        fields::Field::new(label, ComputedManager(Rc::new(compute)), "".to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct ComputedManager(Rc<Fn(&Value) -> String>);
+
+impl WidgetManager for ComputedManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        get_text(view)
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        set_error(view, error)
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(views::TextView::new(value))
+    }
+    fn refresh(&self, view: &mut AnyView, form_data: &Value) {
+        let value = (self.0)(form_data);
+        let boxed_widget = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<views::LinearLayout>()
+            .unwrap();
+        let boxed_field = widget
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let text = (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<views::TextView>()
+            .unwrap();
+        text.set_content(value);
+    }
+}
+
+impl fields::FormField for fields::Field<ComputedManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.initial)
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let data = self.normalized(data);
+        for v in &self.validators {
+            if let Some(e) = v.validate(&data) {
+                return Err(e);
+            }
+        }
+        Ok(Value::String(data))
+    }
+}
@@ -0,0 +1,175 @@
+use cursive::view::AnyView;
+use cursive::views::{LinearLayout, TextView};
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use views;
+use validators::{DatetimeRange, Validator};
+use views::DatetimeKind;
+
+/// Convienient wrapper around `Field<DatetimeManager, String>` editing dates (`YYYY-MM-DD`).
+pub struct Date;
+
+impl Date {
+    /// Creates a new `Field<DatetimeManager, String>`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<DatetimeManager, String> {
+        fields::Field::new(
+            label,
+            DatetimeManager::new(DatetimeKind::Date),
+            "".to_string(),
+        )
+    }
+}
+
+/// Convienient wrapper around `Field<DatetimeManager, String>` editing times (`HH:MM:SS`).
+pub struct Time;
+
+impl Time {
+    /// Creates a new `Field<DatetimeManager, String>`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<DatetimeManager, String> {
+        fields::Field::new(
+            label,
+            DatetimeManager::new(DatetimeKind::Time),
+            "".to_string(),
+        )
+    }
+}
+
+/// Convienient wrapper around `Field<DatetimeManager, String>` editing date with time
+/// (`YYYY-MM-DDTHH:MM:SS`).
+pub struct DateTime;
+
+impl DateTime {
+    /// Creates a new `Field<DatetimeManager, String>`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS) -> fields::Field<DatetimeManager, String> {
+        fields::Field::new(
+            label,
+            DatetimeManager::new(DatetimeKind::DateTime),
+            "".to_string(),
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct DatetimeManager {
+    kind: DatetimeKind,
+    range: DatetimeRange,
+}
+
+impl DatetimeManager {
+    fn new(kind: DatetimeKind) -> Self {
+        DatetimeManager {
+            kind,
+            range: DatetimeRange::new(kind),
+        }
+    }
+}
+
+impl WidgetManager for DatetimeManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let dt = (**boxed_field)
+            .as_any()
+            .downcast_ref::<views::Datetime>()
+            .unwrap();
+        dt.get_value()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let error_field = (*widget)
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap();
+        error_field.set_content(error);
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let mut view = views::Datetime::new(self.kind);
+        if let Some(min) = self.range.min {
+            view = view.min(&min.format(self.kind));
+        }
+        if let Some(max) = self.range.max {
+            view = view.max(&max.format(self.kind));
+        }
+        Box::new(view.value(value))
+    }
+}
+
+impl fields::FormField for fields::Field<DatetimeManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.initial)
+    }
+
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let data = self.normalized(data);
+        let data = data.as_str();
+        if let Some(e) = self.widget_manager.range.validate(data) {
+            return Err(e);
+        }
+        for v in &self.validators {
+            if let Some(e) = v.validate(data) {
+                return Err(e);
+            }
+        }
+        Ok(Value::String(data.to_owned()))
+    }
+
+    /// Gets label of the field
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl fields::Field<DatetimeManager, String> {
+    /// Sets the lowest allowed value (ISO-8601 string like `2018-01-31`).
+    ///
+    /// # Panics
+    ///
+    /// If `min` isn't a valid value of field's kind.
+    pub fn min<IS: Into<String>>(mut self, min: IS) -> Self {
+        self.widget_manager.range = self.widget_manager.range.min(&min.into());
+        self
+    }
+    /// Sets the highest allowed value (ISO-8601 string like `2018-12-31`).
+    ///
+    /// # Panics
+    ///
+    /// If `max` isn't a valid value of field's kind.
+    pub fn max<IS: Into<String>>(mut self, max: IS) -> Self {
+        self.widget_manager.range = self.widget_manager.range.max(&max.into());
+        self
+    }
+}
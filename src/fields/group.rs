@@ -0,0 +1,258 @@
+use std::rc::Rc;
+
+use cursive::view::AnyView;
+use cursive::views::{LinearLayout, Panel, TextView};
+use serde_json;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields::{label_with_help_layout, Field, FormField, WidgetManager};
+
+/// Convienient wrapper around `Field<GroupManager, ()>`.
+///
+/// Groups `fields` (like `FormView` does) and submits them as nested object under own label.
+/// Errors of grouped fields are reported with path, like `database.port: Field is required`.
+/// Validators and normalizers should be added to grouped fields, group's own aren't used.
+pub struct Group;
+
+impl Group {
+    /// Creates a new empty `Field<GroupManager, ()>`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS) -> Field<GroupManager, ()> {
+        let mngr = GroupManager { fields: Vec::new() };
+        Field::new(label, mngr, ())
+    }
+}
+
+#[derive(Clone)]
+pub struct GroupManager {
+    fields: Vec<Rc<FormField>>,
+}
+
+impl GroupManager {
+    fn get_layout<'a>(&self, view: &'a AnyView) -> &'a LinearLayout {
+        let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        (**boxed_field)
+            .as_any()
+            .downcast_ref::<Panel<LinearLayout>>()
+            .unwrap()
+            .get_inner()
+    }
+
+    fn get_layout_mut<'a>(&self, view: &'a mut AnyView) -> &'a mut LinearLayout {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<Panel<LinearLayout>>()
+            .unwrap()
+            .get_inner_mut()
+    }
+}
+
+impl WidgetManager for GroupManager {
+    fn build_value_view(&self, initial: &str) -> Box<AnyView> {
+        let values: Map<String, Value> = serde_json::from_str(initial).unwrap_or_default();
+        let mut layout = LinearLayout::vertical();
+        for field in self.fields.iter() {
+            let label = field.get_label();
+            let widget = match values.get(label).and_then(|v| v.as_str()) {
+                Some(value) => field.get_widget_manager().build_widget(label, "", value),
+                None => field.build_widget(),
+            };
+            layout.add_child(widget);
+        }
+        Box::new(Panel::new(layout))
+    }
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let layout = self.get_layout(view);
+        let mut values = Map::with_capacity(self.fields.len());
+        for (idx, field) in self.fields.iter().enumerate() {
+            let value = field
+                .get_widget_manager()
+                .get_value(layout.get_child(idx).unwrap());
+            values.insert(field.get_label().to_owned(), Value::String(value));
+        }
+        serde_json::to_string(&values).unwrap()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let error_field = (*widget)
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap();
+        error_field.set_content(error);
+    }
+    fn refresh(&self, view: &mut AnyView, form_data: &Value) {
+        let layout = self.get_layout_mut(view);
+        for (idx, field) in self.fields.iter().enumerate() {
+            field
+                .get_widget_manager()
+                .refresh(layout.get_child_mut(idx).unwrap(), form_data);
+        }
+    }
+}
+
+impl FormField for Field<GroupManager, ()> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        let raw: Map<String, Value> = serde_json::from_str(data).unwrap_or_default();
+        let mut warnings = Vec::new();
+        for field in self.widget_manager.fields.iter() {
+            let label = field.get_label();
+            let value = raw.get(label).and_then(|v| v.as_str()).unwrap_or("");
+            for w in field.warnings(value) {
+                warnings.push(self.prefixed(&**field, &w));
+            }
+        }
+        warnings
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        let raw: Map<String, Value> = serde_json::from_str(data).unwrap_or_default();
+        self.widget_manager.fields.iter().any(|field| {
+            let value = raw.get(field.get_label()).and_then(|v| v.as_str()).unwrap_or("");
+            field.is_pending(value)
+        })
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let raw: Map<String, Value> = serde_json::from_str(data)
+            .map_err(|_| "Value can't be converted to group".to_string())?;
+        let mut values = Map::with_capacity(self.widget_manager.fields.len());
+        let mut errors = Vec::new();
+        for field in self.widget_manager.fields.iter() {
+            let label = field.get_label();
+            let value = raw.get(label).and_then(|v| v.as_str()).unwrap_or("");
+            match field.validate(value) {
+                Ok(v) => {
+                    values.insert(label.to_owned(), v);
+                }
+                Err(e) => for line in e.lines() {
+                    errors.push(self.prefixed(&**field, line));
+                },
+            }
+        }
+        if errors.is_empty() {
+            Ok(Value::Object(values))
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn reports_paths(&self) -> bool {
+        true
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, "{}")
+    }
+}
+
+impl Field<GroupManager, ()> {
+    /// Prefixes `line` reported by grouped `field` by path of this group.
+    fn prefixed(&self, field: &FormField, line: &str) -> String {
+        // nested groups report lines which already start with own label
+        if field.reports_paths() {
+            format!("{}.{}", self.label, line)
+        } else {
+            format!("{}.{}: {}", self.label, field.get_label(), line)
+        }
+    }
+    /// Appends `field` to grouped fields.
+    pub fn field<V: FormField + 'static>(mut self, field: V) -> Self {
+        self.widget_manager.fields.push(Rc::new(field));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fields::Text;
+    use validators::{Required, Warn};
+
+    fn port_validator(data: &str) -> Option<String> {
+        match data.parse::<u16>() {
+            Ok(_) => None,
+            Err(_) => Some("Value must be a port number".to_string()),
+        }
+    }
+
+    fn database() -> Field<GroupManager, ()> {
+        Group::new("database")
+            .field(Text::new("host").validator(Required))
+            .field(Text::new("port").validator(port_validator))
+    }
+
+    #[test]
+    fn test_group_reports_errors_with_path() {
+        let field = database();
+        assert_eq!(
+            field.validate(r#"{"host": "", "port": "x"}"#),
+            Err("database.host: Field is required\ndatabase.port: Value must be a port number"
+                .to_string())
+        );
+        assert_eq!(
+            field.validate(r#"{"host": "db", "port": "5432"}"#),
+            Ok(serde_json::from_str(r#"{"host": "db", "port": "5432"}"#).unwrap())
+        );
+        assert!(field.validate("[]").is_err());
+    }
+
+    #[test]
+    fn test_nested_group_reports_full_path() {
+        let field = Group::new("config").field(database());
+        let data = r#"{"database": "{\"host\": \"db\", \"port\": \"x\"}"}"#;
+        assert_eq!(
+            field.validate(data),
+            Err("config.database.port: Value must be a port number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_message_resembling_path_is_prefixed_with_label() {
+        let field = Group::new("config")
+            .field(Text::new("host").validator(|_: &str| Some("host.name is invalid".to_string())))
+            .field(Text::new("port").validator(Warn(port_validator)));
+        assert_eq!(
+            field.validate(r#"{"host": "db", "port": "5432"}"#),
+            Err("config.host: host.name is invalid".to_string())
+        );
+        assert_eq!(
+            field.warnings(r#"{"host": "db", "port": "x"}"#),
+            vec!["config.port: Value must be a port number"]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use cursive::view::AnyView;
+use cursive::views::DummyView;
+use serde_json;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+
+/// Convienient wrapper around `Field<HiddenManager, Value>`.
+///
+/// Field isn't shown, it injects constant `value` into submitted data.
+pub struct Hidden;
+
+impl Hidden {
+    /// Creates a new `Field<HiddenManager, Value>` submitting `value`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>, V: Into<Value>>(
+        label: IS,
+        value: V,
+    ) -> fields::Field<HiddenManager, Value> {
+        let value = value.into();
+        fields::Field::new(label, HiddenManager(value.clone()), value)
+    }
+}
+
+#[derive(Clone)]
+pub struct HiddenManager(Value);
+
+impl WidgetManager for HiddenManager {
+    fn build_widget(&self, _label: &str, _help: &str, initial: &str) -> Box<AnyView> {
+        self.build_value_view(initial)
+    }
+    fn get_value(&self, _view: &AnyView) -> String {
+        serde_json::to_string(&self.0).unwrap()
+    }
+    fn set_error(&self, _view: &mut AnyView, _error: &str) {
+        // no operation, field isn't shown
+    }
+    fn build_value_view(&self, _value: &str) -> Box<AnyView> {
+        Box::new(DummyView)
+    }
+}
+
+impl fields::FormField for fields::Field<HiddenManager, Value> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        match serde_json::from_str(data) {
+            Ok(Value::String(s)) => self.check_warnings(&s),
+            Ok(v) => self.check_warnings(&v.to_string()),
+            Err(_) => Vec::new(),
+        }
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        match serde_json::from_str(data) {
+            Ok(Value::String(s)) => self.check_pending(&s),
+            Ok(v) => self.check_pending(&v.to_string()),
+            Err(_) => false,
+        }
+    }
+    fn current_value(&self, data: &str) -> String {
+        // strings are passed like values of other fields, not as JSON
+        match serde_json::from_str(data) {
+            Ok(Value::String(s)) => s,
+            _ => data.to_string(),
+        }
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        let initial = serde_json::to_string(&self.initial).unwrap();
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &initial)
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let value: Value = serde_json::from_str(data)
+            .map_err(|_| "Value can't be converted to JSON".to_string())?;
+        let (value, text) = match value {
+            Value::String(s) => {
+                let s = self.normalized(&s);
+                (Value::String(s.clone()), s)
+            }
+            v => {
+                let text = v.to_string();
+                (v, text)
+            }
+        };
+        for v in &self.validators {
+            if let Some(e) = v.validate(&text) {
+                return Err(e);
+            }
+        }
+        Ok(value)
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use cursive::traits::Boxable;
+use cursive::view::AnyView;
+use cursive::views::{BoxView, EditView, LinearLayout, TextView};
+use serde_json;
+use serde_json::map::Map;
+use serde_json::value::Value;
+
+use fields::{label_with_help_layout, Field, FormField, WidgetManager};
+use validators::{Severity, Validator};
+use views;
+
+/// Convienient wrapper around `Field<KeyValueManager, Vec<(String, String)>>`.
+///
+/// Rows with empty key and value are skipped, field's normalizers and validators are applied to values.
+pub struct KeyValue;
+
+impl KeyValue {
+    /// Creates a new `Field<KeyValueManager, Vec<(String, String)>>`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS) -> Field<KeyValueManager, Vec<(String, String)>> {
+        let mngr = KeyValueManager {
+            key_validators: Vec::new(),
+            value_validators: Vec::new(),
+            key_warnings: Vec::new(),
+            value_warnings: Vec::new(),
+        };
+        Field::new(label, mngr, Vec::new())
+    }
+}
+
+#[derive(Clone)]
+pub struct KeyValueManager {
+    key_validators: Vec<Rc<Validator>>,
+    value_validators: Vec<Rc<Validator>>,
+    key_warnings: Vec<Rc<Validator>>,
+    value_warnings: Vec<Rc<Validator>>,
+}
+
+fn build_row(value: &str) -> Box<AnyView> {
+    let (key, value): (String, String) = serde_json::from_str(value).unwrap_or_default();
+    let row = LinearLayout::horizontal()
+        .child(EditView::new().content(key).full_width())
+        .child(TextView::new(" = "))
+        .child(EditView::new().content(value).full_width());
+    Box::new(row)
+}
+
+fn read_row(view: &AnyView) -> String {
+    let boxed_row = view.as_any().downcast_ref::<Box<AnyView>>().unwrap();
+    let row = (**boxed_row)
+        .as_any()
+        .downcast_ref::<LinearLayout>()
+        .unwrap();
+    let read_edit = |idx| {
+        let edit = row.get_child(idx)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<BoxView<EditView>>()
+            .unwrap();
+        (*edit.get_inner().get_content()).clone()
+    };
+    serde_json::to_string(&(read_edit(0), read_edit(2))).unwrap()
+}
+
+impl WidgetManager for KeyValueManager {
+    fn build_value_view(&self, initial: &str) -> Box<AnyView> {
+        let pairs: Vec<(String, String)> = serde_json::from_str(initial).unwrap_or_default();
+        let rows = pairs
+            .iter()
+            .map(|pair| serde_json::to_string(pair).unwrap())
+            .collect::<Vec<String>>();
+        let new_row = serde_json::to_string(&("", "")).unwrap();
+        Box::new(views::List::new(build_row, read_row, new_row).values(rows))
+    }
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let list = (**boxed_field)
+            .as_any()
+            .downcast_ref::<views::List>()
+            .unwrap();
+        let pairs = list.get_values()
+            .iter()
+            .map(|row| serde_json::from_str(row).unwrap())
+            .collect::<Vec<(String, String)>>();
+        serde_json::to_string(&pairs).unwrap()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let error_field = (*widget)
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap();
+        error_field.set_content(error);
+    }
+}
+
+impl FormField for Field<KeyValueManager, Vec<(String, String)>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        let pairs: Vec<(String, String)> = serde_json::from_str(data).unwrap_or_default();
+        pairs
+            .iter()
+            .filter(|(key, _)| !key.is_empty())
+            .flat_map(|(key, raw)| {
+                let value = self.normalized(raw);
+                let key_warnings = self.widget_manager
+                    .key_warnings
+                    .iter()
+                    .filter_map(move |v| v.validate(key))
+                    .map(move |w| format!("Key {:?}: {}", key, w));
+                let value_warnings = self.widget_manager
+                    .value_warnings
+                    .iter()
+                    .filter_map(|v| v.validate(&value))
+                    // field's own warnings normalize value on their own
+                    .chain(self.check_warnings(raw))
+                    .map(move |w| format!("Value of {:?}: {}", key, w))
+                    .collect::<Vec<String>>();
+                key_warnings.chain(value_warnings)
+            })
+            .collect()
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        let pairs: Vec<(String, String)> = serde_json::from_str(data).unwrap_or_default();
+        let mngr = &self.widget_manager;
+        pairs.iter().any(|(key, raw)| {
+            let value = self.normalized(raw);
+            let key_pending = mngr.key_validators
+                .iter()
+                .chain(mngr.key_warnings.iter())
+                .any(|v| v.is_pending(key));
+            let value_pending = mngr.value_validators
+                .iter()
+                .chain(mngr.value_warnings.iter())
+                .any(|v| v.is_pending(&value));
+            // field's own validators normalize value on their own
+            key_pending || value_pending || self.check_pending(raw)
+        })
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let pairs: Vec<(String, String)> = serde_json::from_str(data)
+            .map_err(|_| "Value can't be converted to key/value pairs".to_string())?;
+        let mut seen = HashSet::with_capacity(pairs.len());
+        let mut map = Map::with_capacity(pairs.len());
+        for (key, value) in pairs.iter() {
+            let value = &self.normalized(value);
+            if key.is_empty() && value.is_empty() {
+                continue;
+            }
+            if key.is_empty() {
+                return Err(format!("Key of value {:?} is empty", value));
+            }
+            if !seen.insert(key) {
+                return Err(format!("Key {:?} is duplicated", key));
+            }
+            for v in &self.widget_manager.key_validators {
+                if let Some(e) = v.validate(key) {
+                    return Err(format!("Key {:?}: {}", key, e));
+                }
+            }
+            let value_validators = self.widget_manager
+                .value_validators
+                .iter()
+                .chain(self.validators.iter());
+            for v in value_validators {
+                if let Some(e) = v.validate(value) {
+                    return Err(format!("Value of {:?}: {}", key, e));
+                }
+            }
+            map.insert(key.to_owned(), Value::String(value.to_owned()));
+        }
+        Ok(Value::Object(map))
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        let initial = serde_json::to_string(&self.initial).unwrap();
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &initial)
+    }
+}
+
+impl Field<KeyValueManager, Vec<(String, String)>> {
+    /// Sets initial key/value pairs of `field`.
+    pub fn initial<K: Into<String>, V: Into<String>>(mut self, initial: Vec<(K, V)>) -> Self {
+        self.initial = initial
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        self
+    }
+    /// Append `validator` checked against each key.
+    pub fn key_validator<V: Validator + 'static>(mut self, validator: V) -> Self {
+        match validator.severity() {
+            Severity::Error => self.widget_manager.key_validators.push(Rc::new(validator)),
+            Severity::Warning => self.widget_manager.key_warnings.push(Rc::new(validator)),
+        }
+        self
+    }
+    /// Append `validator` checked against each value.
+    pub fn value_validator<V: Validator + 'static>(mut self, validator: V) -> Self {
+        match validator.severity() {
+            Severity::Error => self.widget_manager.value_validators.push(Rc::new(validator)),
+            Severity::Warning => self.widget_manager.value_warnings.push(Rc::new(validator)),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validators::{Required, Warn};
+
+    fn pairs(values: &[(&str, &str)]) -> String {
+        serde_json::to_string(values).unwrap()
+    }
+
+    #[test]
+    fn test_keyvalue_rejects_duplicated_and_empty_keys() {
+        let field = KeyValue::new("env");
+        assert_eq!(
+            field.validate(&pairs(&[("A", "1"), ("A", "2")])),
+            Err("Key \"A\" is duplicated".to_string())
+        );
+        assert_eq!(
+            field.validate(&pairs(&[("", "1")])),
+            Err("Key of value \"1\" is empty".to_string())
+        );
+        let valid = field.validate(&pairs(&[("A", "1"), ("", ""), ("B", "2")]));
+        assert_eq!(valid, Ok(serde_json::from_str(r#"{"A": "1", "B": "2"}"#).unwrap()));
+    }
+
+    #[test]
+    fn test_keyvalue_validates_keys_and_values() {
+        let field = KeyValue::new("env")
+            .key_validator(|key: &str| {
+                if key.chars().all(|c| c.is_uppercase()) {
+                    None
+                } else {
+                    Some("Key must be uppercase".to_string())
+                }
+            })
+            .value_validator(Required)
+            .value_validator(Warn(|value: &str| {
+                if value.len() > 3 {
+                    Some("Value is long".to_string())
+                } else {
+                    None
+                }
+            }));
+        assert_eq!(
+            field.validate(&pairs(&[("a", "1")])),
+            Err("Key \"a\": Key must be uppercase".to_string())
+        );
+        assert_eq!(
+            field.validate(&pairs(&[("A", "")])),
+            Err("Value of \"A\": Field is required".to_string())
+        );
+        assert!(field.validate(&pairs(&[("A", "1234")])).is_ok());
+        assert_eq!(
+            field.warnings(&pairs(&[("A", "1234")])),
+            vec!["Value of \"A\": Value is long"]
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use std::rc::Rc;
+
+use cursive::view::AnyView;
+use cursive::views::{LinearLayout, TextView};
+use serde_json;
+use serde_json::value::Value;
+
+use fields::{label_with_help_layout, Field, FormField, WidgetManager};
+use views;
+
+/// Convienient wrapper around `Field<ListManager, Vec<String>>`.
+///
+/// Each row of the list is edited with its own copy of `field` and normalized and validated by
+/// its normalizers and validators. Normalizers of the list are applied to each row before that
+/// (and before list's validators check the whole collection).
+pub struct List;
+
+impl List {
+    /// Creates a new `Field<ListManager, Vec<String>>` with rows edited by `field`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>, F: FormField + 'static>(
+        label: IS,
+        field: F,
+    ) -> Field<ListManager, Vec<String>> {
+        let mngr = ListManager {
+            field: Rc::new(field),
+            min_items: 0,
+            max_items: None,
+        };
+        Field::new(label, mngr, Vec::new())
+    }
+}
+
+#[derive(Clone)]
+pub struct ListManager {
+    field: Rc<FormField>,
+    min_items: usize,
+    max_items: Option<usize>,
+}
+
+impl ListManager {
+    /// Returns value of `field` when it's untouched.
+    fn new_row_value(&self) -> String {
+        let widget = self.field.build_widget();
+        self.field.get_widget_manager().get_value(&widget)
+    }
+}
+
+impl WidgetManager for ListManager {
+    fn build_value_view(&self, initial: &str) -> Box<AnyView> {
+        let mut rows: Vec<String> = serde_json::from_str(initial).unwrap_or_default();
+        let new_row = self.new_row_value();
+        while rows.len() < self.min_items {
+            rows.push(new_row.clone());
+        }
+        let build_field = Rc::clone(&self.field);
+        let read_field = Rc::clone(&self.field);
+        let mut view = views::List::new(
+            move |value| {
+                build_field
+                    .get_widget_manager()
+                    .build_widget(build_field.get_label(), "", value)
+            },
+            move |view| read_field.get_widget_manager().get_value(view),
+            new_row,
+        ).values(rows);
+        if let Some(max) = self.max_items {
+            view = view.max_rows(max);
+        }
+        Box::new(view)
+    }
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let boxed_widget = (*view).as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let list = (**boxed_field)
+            .as_any()
+            .downcast_ref::<views::List>()
+            .unwrap();
+        serde_json::to_string(&list.get_values()).unwrap()
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let error_field = (*widget)
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap();
+        error_field.set_content(error);
+    }
+    fn refresh(&self, view: &mut AnyView, form_data: &Value) {
+        let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        let boxed_field = (*widget)
+            .get_child_mut(1)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Box<AnyView>>()
+            .unwrap();
+        let list = (**boxed_field)
+            .as_any_mut()
+            .downcast_mut::<views::List>()
+            .unwrap();
+        let manager = self.field.get_widget_manager();
+        list.for_each_row(|row| manager.refresh(row, form_data));
+    }
+}
+
+impl FormField for Field<ListManager, Vec<String>> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        let rows: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        // collection warnings normalize rows on their own
+        let mut warnings = self.check_items_warnings(&rows);
+        let rows = self.normalized_rows(&rows);
+        for (idx, row) in rows.iter().enumerate() {
+            for w in self.widget_manager.field.warnings(row) {
+                warnings.push(format!("Item {}: {}", idx + 1, w));
+            }
+        }
+        warnings
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        let rows: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        if self.check_items_pending(&rows) {
+            return true;
+        }
+        let rows = self.normalized_rows(&rows);
+        rows.iter().any(|row| self.widget_manager.field.is_pending(row))
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let rows: Vec<String> =
+            serde_json::from_str(data).map_err(|_| "Value can't be converted to list".to_string())?;
+        let rows = self.normalized_rows(&rows);
+        if rows.len() < self.widget_manager.min_items {
+            return Err(format!(
+                "At least {} item(s) required",
+                self.widget_manager.min_items
+            ));
+        }
+        if let Some(max) = self.widget_manager.max_items {
+            if rows.len() > max {
+                return Err(format!("At most {} item(s) allowed", max));
+            }
+        }
+        for v in &self.validators {
+            if let Some(e) = v.validate_items(&rows) {
+                return Err(e);
+            }
+        }
+        let mut values = Vec::with_capacity(rows.len());
+        for (idx, row) in rows.iter().enumerate() {
+            let value = self.widget_manager
+                .field
+                .validate(row)
+                .map_err(|e| format!("Item {}: {}", idx + 1, e))?;
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        let initial = serde_json::to_string(&self.initial).unwrap();
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &initial)
+    }
+}
+
+impl Field<ListManager, Vec<String>> {
+    /// Applies normalizers of the list to each of `rows`.
+    fn normalized_rows(&self, rows: &[String]) -> Vec<String> {
+        rows.iter().map(|x| self.normalized(x)).collect()
+    }
+    /// Sets minimal number of items.
+    pub fn min_items(mut self, min_items: usize) -> Self {
+        self.widget_manager.min_items = min_items;
+        self
+    }
+    /// Sets maximal number of items.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.widget_manager.max_items = Some(max_items);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fields::Text;
+    use normalizers::Trim;
+    use validators::{Required, UniqueItems};
+
+    fn rows(values: &[&str]) -> String {
+        serde_json::to_string(values).unwrap()
+    }
+
+    fn json(text: &str) -> Value {
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_list_checks_number_of_rows() {
+        let field = List::new("paths", Text::new("path")).min_items(1).max_items(2);
+        assert_eq!(
+            field.validate(&rows(&[])),
+            Err("At least 1 item(s) required".to_string())
+        );
+        assert_eq!(
+            field.validate(&rows(&["a", "b", "c"])),
+            Err("At most 2 item(s) allowed".to_string())
+        );
+        assert_eq!(field.validate(&rows(&["a", "b"])), Ok(json(r#"["a", "b"]"#)));
+    }
+
+    #[test]
+    fn test_list_reports_row_errors_with_index() {
+        let field = List::new("paths", Text::new("path").validator(Required));
+        assert_eq!(
+            field.validate(&rows(&["a", ""])),
+            Err("Item 2: Field is required".to_string())
+        );
+        assert!(field.validate("not a list").is_err());
+    }
+
+    #[test]
+    fn test_list_normalizes_rows_before_validation() {
+        let field = List::new("paths", Text::new("path"))
+            .normalize(Trim)
+            .validator(UniqueItems);
+        assert!(field.validate(&rows(&["a", " a "])).is_err());
+        assert_eq!(field.validate(&rows(&[" a ", "b"])), Ok(json(r#"["a", "b"]"#)));
+    }
+}
@@ -1,18 +1,39 @@
 //! Includes `form's` building blocks, `fields`.
+use cursive::theme::{BaseColor, Color};
+use cursive::utils::markup::StyledString;
 use cursive::view::AnyView;
 use cursive::views;
 use serde_json::value::Value;
+use normalizers::Normalizer;
 use std::rc::Rc;
-use validators::Validator;
+use validators::{Severity, Validator};
 
 mod autocomplete;
 mod checkbox;
+mod checkbox_group;
+mod computed;
+mod datetime;
+mod group;
+mod hidden;
+mod keyvalue;
+mod list;
 mod multiselect;
+mod readonly;
+mod slider;
 mod text;
 
 pub use self::autocomplete::Autocomplete;
 pub use self::checkbox::Checkbox;
+pub use self::checkbox_group::CheckboxGroup;
+pub use self::computed::Computed;
+pub use self::datetime::{Date, DateTime, Time};
+pub use self::group::Group;
+pub use self::hidden::Hidden;
+pub use self::keyvalue::KeyValue;
+pub use self::list::List;
 pub use self::multiselect::Multiselect;
+pub use self::readonly::ReadOnly;
+pub use self::slider::Slider;
 pub use self::text::Text;
 
 /// Covers communication from `Field` to `Widget`.
@@ -23,8 +44,17 @@ pub trait WidgetManager {
     fn get_value(&self, view: &AnyView) -> String;
     /// Sets `error` on widget.
     fn set_error(&self, view: &mut AnyView, error: &str);
+    /// Sets `warning` on widget.
+    ///
+    /// By default it's styled and shown in place of error of `label_with_help_layout`.
+    fn set_warning(&self, view: &mut AnyView, warning: &str) {
+        let text = StyledString::styled(warning, Color::Dark(BaseColor::Yellow));
+        set_layout_message(view, text);
+    }
     /// Builds a `value` view
     fn build_value_view(&self, value: &str) -> Box<AnyView>;
+    /// Refreshes widget when `form`'s values (not validated, as strings) change.
+    fn refresh(&self, _view: &mut AnyView, _form_data: &Value) {}
 }
 
 /// Building block for `Form`s which stores `data` and `Widget`.
@@ -33,7 +63,9 @@ pub struct Field<W: WidgetManager, T> {
     label: String,
     help: String,
     initial: T,
+    normalizers: Vec<Rc<Normalizer>>,
     validators: Vec<Rc<Validator>>,
+    warnings: Vec<Rc<Validator>>,
     widget_manager: W,
 }
 
@@ -44,7 +76,9 @@ impl<W: WidgetManager, T> Field<W, T> {
             label: label.into(),
             help: "".into(),
             initial: initial,
+            normalizers: vec![],
             validators: vec![],
+            warnings: vec![],
             widget_manager: widget_manager,
         }
     }
@@ -54,10 +88,57 @@ impl<W: WidgetManager, T> Field<W, T> {
         self
     }
     /// Append `validator`.
+    ///
+    /// Validators with `Severity::Warning` (see `validators::Warn`) don't block submitting.
     pub fn validator<V: Validator + 'static>(mut self, validator: V) -> Self {
-        self.validators.push(Rc::new(validator));
+        match validator.severity() {
+            Severity::Error => self.validators.push(Rc::new(validator)),
+            Severity::Warning => self.warnings.push(Rc::new(validator)),
+        }
+        self
+    }
+    /// Append `normalizer`, normalizers transform data (in order) before it's validated.
+    pub fn normalize<N: Normalizer + 'static>(mut self, normalizer: N) -> Self {
+        self.normalizers.push(Rc::new(normalizer));
         self
     }
+    /// Applies all normalizers to `data`.
+    fn normalized(&self, data: &str) -> String {
+        self.normalizers
+            .iter()
+            .fold(data.to_string(), |data, n| n.normalize(&data))
+    }
+    /// Collects warnings for (normalized) `data`.
+    fn check_warnings(&self, data: &str) -> Vec<String> {
+        let data = self.normalized(data);
+        self.warnings
+            .iter()
+            .filter_map(|v| v.validate(&data))
+            .collect()
+    }
+    /// Collects warnings for collection of (normalized) `items`.
+    fn check_items_warnings(&self, items: &[String]) -> Vec<String> {
+        let items = items
+            .iter()
+            .map(|x| self.normalized(x))
+            .collect::<Vec<String>>();
+        self.warnings
+            .iter()
+            .filter_map(|v| v.validate_items(&items))
+            .collect()
+    }
+    /// Checks if any validator (or warning) still checks (normalized) `data` in background.
+    fn check_pending(&self, data: &str) -> bool {
+        let data = self.normalized(data);
+        self.validators
+            .iter()
+            .chain(self.warnings.iter())
+            .any(|v| v.is_pending(&data))
+    }
+    /// Checks if any validator (or warning) still checks some of (normalized) `items`.
+    fn check_items_pending(&self, items: &[String]) -> bool {
+        items.iter().any(|x| self.check_pending(x))
+    }
 }
 
 /// Covers communication from `Form` to `Field`.
@@ -70,6 +151,24 @@ pub trait FormField {
     fn get_label(&self) -> &str;
     /// Gets manager which controlls `widget`.
     fn get_widget_manager(&self) -> &WidgetManager;
+    /// Gets warnings for `data`, they are checked only when `data` is valid.
+    fn warnings(&self, _data: &str) -> Vec<String> {
+        Vec::new()
+    }
+    /// Checks if validation of `data` still runs in background.
+    fn is_pending(&self, _data: &str) -> bool {
+        false
+    }
+    /// Checks if messages of this field are lines starting with path of its invalid subfield
+    /// (like `database.port: Field is required` of `Group`).
+    fn reports_paths(&self) -> bool {
+        false
+    }
+    /// Converts `data` (read from widget) to form's current value seen by other fields (like
+    /// `Computed`).
+    fn current_value(&self, data: &str) -> String {
+        data.to_string()
+    }
 }
 
 fn format_annotation(label: &str, help: &str) -> String {
@@ -91,3 +190,15 @@ pub fn label_with_help_layout(view: Box<AnyView>, label: &str, help: &str) -> Bo
 
     Box::new(widget)
 }
+
+/// Sets `text` in place of error of `label_with_help_layout`, other layouts are skipped.
+fn set_layout_message(view: &mut AnyView, text: StyledString) {
+    let message = view.as_any_mut()
+        .downcast_mut::<Box<AnyView>>()
+        .and_then(|widget| (**widget).as_any_mut().downcast_mut::<views::LinearLayout>())
+        .and_then(|layout| layout.get_child_mut(2))
+        .and_then(|child| child.as_any_mut().downcast_mut::<views::TextView>());
+    if let Some(message) = message {
+        message.set_content(text);
+    }
+}
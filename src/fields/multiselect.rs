@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use cursive::view::AnyView;
 use cursive::views::{LinearLayout, TextView};
+use serde_json;
 use serde_json::value::Value;
 
 use feeders::Feeder;
@@ -33,13 +34,8 @@ pub struct MultiselectManager {
 impl WidgetManager for MultiselectManager {
     fn build_value_view(&self, initial: &str) -> Box<AnyView> {
         let mut widget = views::Multiselect::new(Rc::clone(&self.feeder));
-        if initial.trim() != "" {
-            let items = initial
-                .split(",")
-                .map(|x| x.to_owned())
-                .collect::<Vec<String>>();
-            widget.select_items(items);
-        }
+        let items: Vec<String> = serde_json::from_str(initial).unwrap_or_default();
+        widget.select_items(items);
         Box::new(widget)
     }
     fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
@@ -63,11 +59,7 @@ impl WidgetManager for MultiselectManager {
             .downcast_ref::<views::Multiselect>()
             .unwrap();
 
-        let result: Vec<String> = ms.get_selected_items()
-            .iter()
-            .map(|x| (*x).to_owned())
-            .collect();
-        result.join(",")
+        serde_json::to_string(&ms.get_selected_items()).unwrap()
     }
     fn set_error(&self, view: &mut AnyView, error: &str) {
         let boxed_widget = (*view).as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
@@ -89,18 +81,29 @@ impl FormField for Field<MultiselectManager, Vec<String>> {
     fn get_widget_manager(&self) -> &WidgetManager {
         &self.widget_manager
     }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        let items: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        self.check_items_warnings(&items)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        let items: Vec<String> = serde_json::from_str(data).unwrap_or_default();
+        self.check_items_pending(&items)
+    }
     fn validate(&self, data: &str) -> Result<Value, String> {
-        let items = data.split(",").collect::<Vec<&str>>();
-        for item in items.iter() {
-            for v in &self.validators {
-                if let Some(e) = v.validate(item) {
-                    return Err(e);
-                }
+        let items: Vec<String> = serde_json::from_str(data)
+            .map_err(|_| "Value can't be converted to list".to_string())?;
+        let items = items
+            .iter()
+            .map(|x| self.normalized(x))
+            .collect::<Vec<String>>();
+        for v in &self.validators {
+            if let Some(e) = v.validate_items(&items) {
+                return Err(e);
             }
         }
         let vec_str = items
-            .iter()
-            .map(|x| Value::String(x.to_string()))
+            .into_iter()
+            .map(Value::String)
             .collect::<Vec<Value>>();
         let val_of_vec = Value::Array(vec_str);
         Ok(val_of_vec)
@@ -109,7 +112,7 @@ impl FormField for Field<MultiselectManager, Vec<String>> {
         &self.label
     }
     fn build_widget(&self) -> Box<AnyView> {
-        let initial = self.initial.join(",");
+        let initial = serde_json::to_string(&self.initial).unwrap();
         self.widget_manager
             .build_widget(&self.label, &self.help, &initial)
     }
@@ -0,0 +1,106 @@
+use cursive::view::AnyView;
+use cursive::views;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+
+/// Convienient wrapper around `Field<ReadOnlyManager, String>`.
+///
+/// Field shows `value` (like detected git branch) which can't be edited.
+pub struct ReadOnly;
+
+impl ReadOnly {
+    /// Creates a new `Field<ReadOnlyManager, String>` showing `value`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>, V: Into<String>>(
+        label: IS,
+        value: V,
+    ) -> fields::Field<ReadOnlyManager, String> {
+        fields::Field::new(label, ReadOnlyManager, value.into())
+    }
+}
+
+/// Reads text shown by widget built with `label_with_help_layout` around `TextView`.
+pub(super) fn get_text(view: &AnyView) -> String {
+    let boxed_widget = view.as_any().downcast_ref::<Box<AnyView>>().unwrap();
+    let widget = (**boxed_widget)
+        .as_any()
+        .downcast_ref::<views::LinearLayout>()
+        .unwrap();
+    let boxed_field = widget
+        .get_child(1)
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Box<AnyView>>()
+        .unwrap();
+    let text = (**boxed_field)
+        .as_any()
+        .downcast_ref::<views::TextView>()
+        .unwrap();
+    let content = text.get_content();
+    content.source().to_owned()
+}
+
+/// Sets error of widget built with `label_with_help_layout`.
+pub(super) fn set_error(view: &mut AnyView, error: &str) {
+    let view = view.as_any_mut().downcast_mut::<Box<AnyView>>().unwrap();
+    let layout = (**view)
+        .as_any_mut()
+        .downcast_mut::<views::LinearLayout>()
+        .unwrap();
+    let text = layout
+        .get_child_mut(2)
+        .unwrap()
+        .as_any_mut()
+        .downcast_mut::<views::TextView>()
+        .unwrap();
+    text.set_content(error);
+}
+
+#[derive(Clone)]
+pub struct ReadOnlyManager;
+
+impl WidgetManager for ReadOnlyManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        get_text(view)
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        set_error(view, error)
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        Box::new(views::TextView::new(value))
+    }
+}
+
+impl fields::FormField for fields::Field<ReadOnlyManager, String> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &self.initial)
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let data = self.normalized(data);
+        for v in &self.validators {
+            if let Some(e) = v.validate(&data) {
+                return Err(e);
+            }
+        }
+        Ok(Value::String(data))
+    }
+}
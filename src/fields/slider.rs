@@ -0,0 +1,112 @@
+use cursive::view::AnyView;
+use cursive::views::LinearLayout;
+use serde_json::value::Value;
+
+use fields;
+use fields::WidgetManager;
+use fields::readonly::set_error;
+use views;
+
+/// Convienient wrapper around `Field<SliderManager, i64>`.
+pub struct Slider;
+
+impl Slider {
+    /// Creates a new `Field<SliderManager, i64>` picking numbers from `min` to `max`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new<IS: Into<String>>(label: IS, min: i64, max: i64) -> fields::Field<SliderManager, i64> {
+        let mngr = SliderManager {
+            min,
+            max,
+            step: 1,
+        };
+        fields::Field::new(label, mngr, min)
+    }
+}
+
+#[derive(Clone)]
+pub struct SliderManager {
+    min: i64,
+    max: i64,
+    step: i64,
+}
+
+impl WidgetManager for SliderManager {
+    fn build_widget(&self, label: &str, help: &str, initial: &str) -> Box<AnyView> {
+        let view = self.build_value_view(initial);
+        fields::label_with_help_layout(view, label, help)
+    }
+    fn get_value(&self, view: &AnyView) -> String {
+        let boxed_widget = view.as_any().downcast_ref::<Box<AnyView>>().unwrap();
+        let widget = (**boxed_widget)
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let boxed_field = widget
+            .get_child(1)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Box<AnyView>>()
+            .unwrap();
+        let slider = (**boxed_field)
+            .as_any()
+            .downcast_ref::<views::Slider>()
+            .unwrap();
+        format!("{}", slider.get_value())
+    }
+    fn set_error(&self, view: &mut AnyView, error: &str) {
+        set_error(view, error)
+    }
+    fn build_value_view(&self, value: &str) -> Box<AnyView> {
+        let value = value.parse().unwrap_or(self.min);
+        Box::new(views::Slider::new(self.min, self.max, self.step).value(value))
+    }
+}
+
+impl fields::FormField for fields::Field<SliderManager, i64> {
+    fn get_widget_manager(&self) -> &WidgetManager {
+        &self.widget_manager
+    }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
+    fn build_widget(&self) -> Box<AnyView> {
+        let initial = format!("{}", self.initial);
+        self.widget_manager
+            .build_widget(&self.label, &self.help, &initial)
+    }
+    fn get_label(&self) -> &str {
+        &self.label
+    }
+    fn validate(&self, data: &str) -> Result<Value, String> {
+        let data = self.normalized(data);
+        let value: i64 = data.parse()
+            .map_err(|_| "Value can't be converted to number".to_string())?;
+        for v in &self.validators {
+            if let Some(e) = v.validate(&data) {
+                return Err(e);
+            }
+        }
+        Ok(Value::from(value))
+    }
+}
+
+impl<W: WidgetManager> fields::Field<W, i64> {
+    /// Sets initial `value` of `field`.
+    pub fn initial(mut self, value: i64) -> Self {
+        self.initial = value;
+        self
+    }
+}
+
+impl fields::Field<SliderManager, i64> {
+    /// Sets difference between two closest values (default is 1).
+    ///
+    /// When `max - min` isn't a multiple of `step`, `max` is rounded down to the last step.
+    pub fn step(mut self, step: i64) -> Self {
+        self.widget_manager.step = step;
+        self
+    }
+}
@@ -66,18 +66,25 @@ impl fields::FormField for fields::Field<TextManager, String> {
     fn get_widget_manager(&self) -> &WidgetManager {
         &self.widget_manager
     }
+    fn warnings(&self, data: &str) -> Vec<String> {
+        self.check_warnings(data)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.check_pending(data)
+    }
     fn build_widget(&self) -> Box<AnyView> {
         self.widget_manager
             .build_widget(&self.label, &self.help, &self.initial)
     }
 
     fn validate(&self, data: &str) -> Result<Value, String> {
+        let data = self.normalized(data);
         for v in &self.validators {
-            if let Some(e) = v.validate(data) {
+            if let Some(e) = v.validate(&data) {
                 return Err(e);
             }
         }
-        Ok(Value::String(data.to_owned()))
+        Ok(Value::String(data))
     }
 
     /// Gets label of the field
@@ -21,6 +21,10 @@ pub struct FormView {
     fields: Vec<Box<FormField>>,
     on_submit: OnSubmit,
     on_cancel: OnCancel,
+    /// Submit waits for validation running in background.
+    pending_submit: bool,
+    /// Current values of fields seen by the last refresh.
+    refreshed_data: Option<Value>,
 }
 impl FormView {
     /// Creates a new `FormView` with two buttons `submit` and `cancel`.
@@ -34,6 +38,8 @@ impl FormView {
             fields: Vec::new(),
             on_submit: None,
             on_cancel: None,
+            pending_submit: false,
+            refreshed_data: None,
         }
     }
 
@@ -47,6 +53,7 @@ impl FormView {
             .unwrap()
             .add_child(widget);
         self.fields.push(Box::new(field));
+        self.refresh_fields();
         self
     }
 
@@ -88,6 +95,42 @@ impl FormView {
         self
     }
 
+    /// Lets `fields` refresh their widgets with current (not validated) values of the form.
+    ///
+    /// Widgets are refreshed only when some value changed since the last refresh.
+    fn refresh_fields(&mut self) {
+        let mut data = Map::with_capacity(self.fields.len());
+        {
+            let layout = self.view
+                .get_content()
+                .as_any()
+                .downcast_ref::<LinearLayout>()
+                .unwrap();
+            for (idx, field) in self.fields.iter().enumerate() {
+                let value = field
+                    .get_widget_manager()
+                    .get_value(layout.get_child(idx).unwrap());
+                let value = field.current_value(&value);
+                data.insert(field.get_label().to_owned(), Value::String(value));
+            }
+        }
+        let data = Value::Object(data);
+        if self.refreshed_data.as_ref() == Some(&data) {
+            return;
+        }
+        let layout = self.view
+            .get_content_mut()
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap();
+        for (idx, field) in self.fields.iter().enumerate() {
+            field
+                .get_widget_manager()
+                .refresh(layout.get_child_mut(idx).unwrap(), &data);
+        }
+        self.refreshed_data = Some(data);
+    }
+
     fn validate(&self) -> Result<Value, HashMap<String, String>> {
         let mut data = Map::with_capacity(self.fields.len());
         let mut errors = HashMap::with_capacity(self.fields.len());
@@ -119,8 +162,100 @@ impl FormView {
         }
     }
 
+    /// Collects warnings of fields which have no `errors`.
+    fn warnings(&self, errors: &HashMap<String, String>) -> Vec<(String, String)> {
+        let layout = self.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let mut warnings = Vec::new();
+        for (idx, field) in self.fields.iter().enumerate() {
+            if errors.contains_key(field.get_label()) {
+                continue;
+            }
+            let value = field
+                .get_widget_manager()
+                .get_value(layout.get_child(idx).unwrap());
+            let field_warnings = field.warnings(&value);
+            if !field_warnings.is_empty() {
+                warnings.push((field.get_label().to_owned(), field_warnings.join("\n")));
+            }
+        }
+        warnings
+    }
+
+    /// Checks if validation of any field still runs in background.
+    fn is_pending(&self) -> bool {
+        let layout = self.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        self.fields.iter().enumerate().any(|(idx, field)| {
+            let value = field
+                .get_widget_manager()
+                .get_value(layout.get_child(idx).unwrap());
+            field.is_pending(&value)
+        })
+    }
+
     fn event_submit(&mut self) -> EventResult {
-        match self.validate() {
+        let result = self.validate();
+        let warnings = match result {
+            Err(ref errors) => self.warnings(errors),
+            Ok(_) => self.warnings(&HashMap::new()),
+        };
+        for (idx, field) in self.fields.iter().enumerate() {
+            let label = field.get_label();
+            let warning = warnings.iter().find(|(l, _)| l == label);
+            let view = self.view
+                .get_content_mut()
+                .as_any_mut()
+                .downcast_mut::<LinearLayout>()
+                .unwrap()
+                .get_child_mut(idx)
+                .unwrap();
+            match warning {
+                Some((_, w)) => field.get_widget_manager().set_warning(view, w),
+                None => field.get_widget_manager().set_error(view, ""),
+            }
+        }
+        // submit again once background validation finishes (see `Event::Refresh`)
+        self.pending_submit = self.is_pending();
+        if self.pending_submit {
+            if let Err(ref errors) = result {
+                self.set_errors(errors);
+            }
+            return EventResult::Consumed(None);
+        }
+        match result {
+            Ok(ref data_map) if !warnings.is_empty() => {
+                let on_submit = self.on_submit.clone();
+                let data_map = data_map.clone();
+                let text = warnings
+                    .iter()
+                    .map(|(label, w)| format!("{}: {}", label, w))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                let cb = Callback::from_fn(move |c| {
+                    let on_submit = on_submit.clone();
+                    let data_map = data_map.clone();
+                    let dialog = Dialog::text(text.clone())
+                        .title("Submit despite warnings?")
+                        .button("Back", |c| {
+                            c.pop_layer();
+                        })
+                        .button("Submit", move |c| {
+                            c.pop_layer();
+                            if let Some(ref cb) = on_submit {
+                                cb(c, data_map.clone());
+                            }
+                        });
+                    c.add_layer(dialog);
+                });
+                EventResult::Consumed(Some(cb))
+            }
             Ok(data_map) => {
                 let opt_cb = self.on_submit
                     .clone()
@@ -129,31 +264,40 @@ impl FormView {
             }
             Err(errors) => {
                 // TODO: the event focus next required/invalid field?
-                for (idx, field) in self.fields.iter().enumerate() {
-                    let label = field.get_label();
-                    let e = errors.get(label).map(|x| x.as_ref()).unwrap_or("");
-                    // can't call method which returns suitable view because of ownership
-                    //  * such method would get &mut self
-                    //  * self.field gets &self
-                    //  so this clash of &mut and &, illegal
-                    //  possible solution is to use clone on WidgetManager (needs implementation)
-                    //  or
-                    //  form should only call field.validate and rest would be handled by field
-                    //  which should solve this issue?
-                    let mut view = self.view
-                        .get_content_mut()
-                        .as_any_mut()
-                        .downcast_mut::<LinearLayout>()
-                        .unwrap()
-                        .get_child_mut(idx)
-                        .unwrap();
-                    field.get_widget_manager().set_error(view, e);
-                }
+                self.set_errors(&errors);
                 EventResult::Consumed(None)
             }
         }
     }
 
+    /// Shows `errors` of fields, fields without error keep their warnings.
+    fn set_errors(&mut self, errors: &HashMap<String, String>) {
+        for (idx, field) in self.fields.iter().enumerate() {
+            let label = field.get_label();
+            let e = match errors.get(label) {
+                Some(e) => e.as_ref(),
+                // keep warning shown
+                None => continue,
+            };
+            // can't call method which returns suitable view because of ownership
+            //  * such method would get &mut self
+            //  * self.field gets &self
+            //  so this clash of &mut and &, illegal
+            //  possible solution is to use clone on WidgetManager (needs implementation)
+            //  or
+            //  form should only call field.validate and rest would be handled by field
+            //  which should solve this issue?
+            let view = self.view
+                .get_content_mut()
+                .as_any_mut()
+                .downcast_mut::<LinearLayout>()
+                .unwrap()
+                .get_child_mut(idx)
+                .unwrap();
+            field.get_widget_manager().set_error(view, e);
+        }
+    }
+
     fn event_cancel(&mut self) -> EventResult {
         let cb = self.on_cancel
             .clone()
@@ -166,7 +310,13 @@ impl ViewWrapper for FormView {
     wrap_impl!(self.view: Dialog);
 
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
-        match event {
+        // regular refresh (see `Cursive::set_fps`) doesn't change values
+        let user_event = event != Event::Refresh;
+        if user_event {
+            // changes made meanwhile are submitted only on demand
+            self.pending_submit = false;
+        }
+        let result = match event {
             Event::Mouse {
                 offset,
                 position,
@@ -192,11 +342,67 @@ impl ViewWrapper for FormView {
             },
             // TODO: ctlr+enter binding?
             Event::CtrlChar('f') => self.event_submit(),
+            // background validation may have finished
+            Event::Refresh if self.pending_submit && !self.is_pending() => self.event_submit(),
             _ => {
                 // default behaviour from ViewWrapper
                 self.with_view_mut(|v| v.on_event(event))
                     .unwrap_or(EventResult::Ignored)
             }
+        };
+        if user_event {
+            self.refresh_fields();
         }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    use fields::{Computed, Hidden, List, Text};
+
+    #[test]
+    fn test_computed_fields_are_refreshed_only_when_values_change() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let seen_clone = Rc::clone(&seen);
+        let mut form = FormView::new()
+            .field(Hidden::new("hidden", "x"))
+            .field(Computed::new("computed", move |data: &Value| {
+                let hidden = data["hidden"].as_str().unwrap_or("").to_string();
+                seen_clone.borrow_mut().push(hidden.clone());
+                hidden
+            }));
+        assert_eq!(seen.borrow().last().unwrap(), "x");
+        // value of computed field itself changed
+        form.on_event(Event::Key(Key::Down));
+        let refreshed = seen.borrow().len();
+
+        form.on_event(Event::Refresh);
+        form.on_event(Event::Key(Key::Down));
+        assert_eq!(seen.borrow().len(), refreshed);
+    }
+
+    #[test]
+    fn test_computed_fields_in_list_are_refreshed() {
+        let form = FormView::new()
+            .field(Text::new("name").initial("fui"))
+            .field(List::new(
+                "rows",
+                Computed::new("row", |data: &Value| {
+                    format!("{}!", data["name"].as_str().unwrap_or(""))
+                }),
+            ).initial(vec![""]));
+        let layout = form.view
+            .get_content()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap();
+        let value = form.fields[1]
+            .get_widget_manager()
+            .get_value(layout.get_child(1).unwrap());
+        assert_eq!(value, r#"["fui!"]"#);
     }
 }
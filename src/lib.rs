@@ -117,8 +117,13 @@
 #[macro_use]
 extern crate cursive as _cursive;
 extern crate glob;
+#[macro_use]
+extern crate lazy_static;
+#[cfg(unix)]
+extern crate libc;
 extern crate regex;
 extern crate serde_json;
+extern crate unicode_width;
 
 /// Re-export of [Cursive](../cursive/index.html) crate.
 pub mod cursive {
@@ -128,6 +133,7 @@ pub use serde_json::value::Value;
 pub mod feeders;
 pub mod fields;
 pub mod form;
+pub mod normalizers;
 pub mod utils;
 pub mod validators;
 pub mod views;
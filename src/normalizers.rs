@@ -0,0 +1,115 @@
+//! Provides data normalizers used by `fields`.
+//!
+//! Normalizers transform data before it's validated and submitted.
+use utils::{expand_tilde, normalize_path};
+
+/// Adds behaviour of normalization.
+pub trait Normalizer {
+    /// Returns normalized `data`.
+    fn normalize(&self, data: &str) -> String;
+}
+
+/// Normalizes data with closure (or function).
+///
+/// Examples
+///
+/// ```
+/// use fui::normalizers::Normalizer;
+///
+/// let n = |data: &str| data.replace('\\', "/");
+/// assert_eq!(n.normalize("some\\path"), "some/path");
+/// ```
+impl<F> Normalizer for F
+where
+    F: Fn(&str) -> String,
+{
+    fn normalize(&self, data: &str) -> String {
+        self(data)
+    }
+}
+
+/// Removes leading and trailing whitespaces.
+///
+/// Examples
+///
+/// ```
+/// use fui::normalizers::{Normalizer, Trim};
+///
+/// assert_eq!(Trim.normalize("  some text\n"), "some text");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Trim;
+
+impl Normalizer for Trim {
+    fn normalize(&self, data: &str) -> String {
+        data.trim().to_string()
+    }
+}
+
+/// Converts data to lowercase.
+///
+/// Examples
+///
+/// ```
+/// use fui::normalizers::{Lowercase, Normalizer};
+///
+/// assert_eq!(Lowercase.normalize("Some Text"), "some text");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lowercase;
+
+impl Normalizer for Lowercase {
+    fn normalize(&self, data: &str) -> String {
+        data.to_lowercase()
+    }
+}
+
+/// Replaces leading `~` with home-dir path.
+///
+/// Examples
+///
+/// ```
+/// use fui::normalizers::{ExpandTilde, Normalizer};
+/// use fui::utils::home_dir;
+///
+/// assert_eq!(ExpandTilde.normalize("~/.bashrc"), format!("{}/.bashrc", home_dir()));
+/// assert_eq!(ExpandTilde.normalize("./~"), "./~");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExpandTilde;
+
+impl Normalizer for ExpandTilde {
+    fn normalize(&self, data: &str) -> String {
+        expand_tilde(data)
+    }
+}
+
+/// Makes path absolute, resolving `~`, `.`, `..` and symlinks (when path exists).
+///
+/// Empty data is left untouched (so it can be checked by `Required`).
+///
+/// Examples
+///
+/// ```
+/// use fui::normalizers::{Canonicalize, Normalizer};
+/// use fui::utils::cwd;
+///
+/// assert_eq!(Canonicalize.normalize("./src/../Cargo.toml"), format!("{}/Cargo.toml", cwd()));
+/// assert_eq!(Canonicalize.normalize("new-dir/./file"), format!("{}/new-dir/file", cwd()));
+/// assert_eq!(Canonicalize.normalize(""), "");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Canonicalize;
+
+impl Normalizer for Canonicalize {
+    fn normalize(&self, data: &str) -> String {
+        if data.is_empty() {
+            return data.to_string();
+        }
+        let path = normalize_path(data);
+        // keep data if path can't be represented as string
+        path.to_str()
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| data.to_string())
+    }
+}
@@ -1,5 +1,11 @@
 //! Various kinds of helpers.
 use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
 
 /// Returns current working dir as String.
 pub fn cwd() -> String {
@@ -13,3 +19,211 @@ pub fn cwd() -> String {
 pub fn home_dir() -> String {
     env::home_dir().map(|p| p.into_os_string().into_string().unwrap()).unwrap()
 }
+
+/// Replaces `~` (whole `path` or its leading `~/`) with home-dir path.
+///
+/// Paths of other users' homes (like `~bob/x`) aren't changed.
+pub fn expand_tilde(path: &str) -> String {
+    if path == "~" {
+        home_dir()
+    } else if path.starts_with("~/") {
+        format!("{}{}", home_dir(), &path[1..])
+    } else {
+        path.to_string()
+    }
+}
+
+/// Limit of followed symlinks (like the one of Linux), stops resolving symlink loops.
+const MAX_SYMLINKS: usize = 40;
+
+/// Makes `path` absolute (expanding `~`), resolves `.`, `..` and symlinks.
+///
+/// Components are resolved one by one, so `..` after symlink refers to symlink target's parent
+/// and symlinks inside existing part of not existing path are resolved too.
+pub fn normalize_path(path: &str) -> PathBuf {
+    let path = PathBuf::from(expand_tilde(path));
+    let path = if path.is_absolute() {
+        path
+    } else {
+        env::current_dir().map(|cwd| cwd.join(&path)).unwrap_or(path)
+    };
+    let mut followed = 0;
+    resolve_components(&path, &mut followed)
+}
+
+fn resolve_components(path: &Path, followed: &mut usize) -> PathBuf {
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            Component::Normal(name) => {
+                resolved.push(name);
+                let is_symlink = fs::symlink_metadata(&resolved)
+                    .map(|m| m.file_type().is_symlink())
+                    .unwrap_or(false);
+                if !is_symlink {
+                    continue;
+                }
+                *followed += 1;
+                let target = match fs::read_link(&resolved) {
+                    Ok(target) => target,
+                    Err(_) => continue,
+                };
+                if *followed > MAX_SYMLINKS {
+                    continue;
+                }
+                resolved.pop();
+                // target may not exist (dangling symlink), so it's resolved like any path
+                let target = resolved.join(target);
+                resolved = resolve_components(&target, followed);
+            }
+            c => resolved.push(c.as_os_str()),
+        }
+    }
+    resolved
+}
+
+/// Runs `program` with `args` (passing `input` to its stdin) and returns its stdout.
+///
+/// Command is killed when it doesn't finish before `timeout`. Error is stderr of failed command
+/// (or description of failure when stderr is empty).
+pub fn run_command(
+    program: &str,
+    args: &[String],
+    input: Option<&str>,
+    timeout: Duration,
+) -> Result<String, String> {
+    let mut child = process::Command::new(program)
+        .args(args)
+        .stdin(if input.is_some() {
+            process::Stdio::piped()
+        } else {
+            process::Stdio::null()
+        })
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Command {:?} can't be run: {}", program, e))?;
+    if let (Some(mut stdin), Some(input)) = (child.stdin.take(), input) {
+        let input = input.to_string();
+        // command may not read stdin, so don't wait for writing
+        thread::spawn(move || stdin.write_all(input.as_bytes()));
+    }
+    // pipes are drained in threads, so filled pipe buffer doesn't block command
+    let mut stdout = child.stdout.take().unwrap();
+    let stdout = thread::spawn(move || {
+        let mut text = String::new();
+        let _ = stdout.read_to_string(&mut text);
+        text
+    });
+    let mut stderr = child.stderr.take().unwrap();
+    let stderr = thread::spawn(move || {
+        let mut text = String::new();
+        let _ = stderr.read_to_string(&mut text);
+        text
+    });
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() < timeout => {
+                thread::sleep(Duration::from_millis(10));
+            }
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("Command timed out after {:?}", timeout));
+            }
+            Err(e) => return Err(format!("Command {:?} failed: {}", program, e)),
+        }
+    };
+    if status.success() {
+        return Ok(stdout.join().unwrap_or_default());
+    }
+    let text = stderr.join().unwrap_or_default();
+    let text = text.trim();
+    if text.is_empty() {
+        Err(format!("Command {:?} failed ({})", program, status))
+    } else {
+        Err(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_tilde_expands_only_own_home() {
+        let home = home_dir();
+        assert_eq!(expand_tilde("~"), home);
+        assert_eq!(expand_tilde("~/x"), format!("{}/x", home));
+        assert_eq!(expand_tilde("~bob/x"), "~bob/x");
+        assert_eq!(expand_tilde("a/~/x"), "a/~/x");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_normalize_path_resolves_symlinks_before_parent_dirs() {
+        use std::os::unix::fs::symlink;
+        use validators::{Validator, WithinDir};
+
+        let root = env::temp_dir().join(format!("fui-test-normalize-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let inside = root.join("inside");
+        let outside = root.join("outside");
+        fs::create_dir_all(inside.join("sub")).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        let root = fs::canonicalize(&root).unwrap();
+        let (inside, outside) = (root.join("inside"), root.join("outside"));
+        symlink(&outside, inside.join("link")).unwrap();
+        symlink(outside.join("missing"), inside.join("dangling")).unwrap();
+        let path = |p: &Path| p.to_str().unwrap().to_string();
+
+        assert_eq!(
+            normalize_path(&path(&inside.join("link/new-file"))),
+            outside.join("new-file")
+        );
+        assert_eq!(
+            normalize_path(&path(&inside.join("link/new-dir/../new-file"))),
+            outside.join("new-file")
+        );
+        assert_eq!(normalize_path(&path(&inside.join("link/.."))), root.clone());
+        assert_eq!(
+            normalize_path(&path(&inside.join("dangling/new-file"))),
+            outside.join("missing/new-file")
+        );
+        assert_eq!(
+            normalize_path(&path(&inside.join("sub/../new-file"))),
+            inside.join("new-file")
+        );
+
+        let within = WithinDir(path(&inside));
+        assert!(within.validate(&path(&inside.join("link/new-file"))).is_some());
+        assert!(within.validate(&path(&inside.join("link/../outside/x"))).is_some());
+        assert_eq!(within.validate(&path(&inside.join("link/../inside/x"))), None);
+        assert_eq!(within.validate(&path(&inside.join("sub/new-file"))), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_normalize_path_stops_on_symlink_loop() {
+        use std::os::unix::fs::symlink;
+
+        let root = env::temp_dir().join(format!("fui-test-loop-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let root = fs::canonicalize(&root).unwrap();
+        symlink(root.join("b"), root.join("a")).unwrap();
+        symlink(root.join("a"), root.join("b")).unwrap();
+
+        normalize_path(root.join("a/x").to_str().unwrap());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,13 +1,87 @@
 //! Provides data validators used by `fields`.
+//!
+//! Error messages of validators can be replaced with `WithMessage`.
 //TODO:: Simplify examples here
+use cursive::{CbFunc, Cursive};
+use cursive::event::Event;
+use cursive::view::View;
 use regex::Regex;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::fs;
+use std::io::ErrorKind;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+
+use utils::{expand_tilde, normalize_path, run_command};
+use views::{parse_bound, DatetimeKind, Stamp};
+
+/// Severity of validator's message.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    /// Data is invalid and can't be submitted.
+    Error,
+    /// Data is suspicious, it can be submitted after user's confirmation.
+    Warning,
+}
 
 /// Adds behaviour of validation.
 pub trait Validator {
     /// Validates data returning None (when Ok) or String with error.
     fn validate(&self, data: &str) -> Option<String>;
+    /// Validates collection of `items` (like `Multiselect`'s selection) returning None (when Ok)
+    /// or String with error.
+    ///
+    /// By default each item is validated separately and the first error is returned.
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        items.iter().filter_map(|x| self.validate(x)).next()
+    }
+    /// Returns severity of messages returned by this validator.
+    ///
+    /// By default messages are errors, see `Warn` for warnings.
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+    /// Checks if validation of `data` still runs in background, meanwhile `validate` returns
+    /// message describing the pending check.
+    ///
+    /// By default validation finishes immediately.
+    fn is_pending(&self, _data: &str) -> bool {
+        false
+    }
+}
+
+/// Validates data with closure (or function) returning None (when Ok) or String with error.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::Validator;
+///
+/// let allowed_ports = vec!["80", "443"];
+/// let v = move |data: &str| {
+///     if allowed_ports.contains(&data) {
+///         None
+///     } else {
+///         Some(format!("Port must be one of: {}", allowed_ports.join(", ")))
+///     }
+/// };
+/// assert_eq!(v.validate("443"), None);
+/// assert_eq!(v.validate("22"), Some("Port must be one of: 80, 443".to_string()));
+/// ```
+impl<F> Validator for F
+where
+    F: Fn(&str) -> Option<String>,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        self(data)
+    }
 }
 
 /// Ensures data is included.
@@ -20,6 +94,10 @@ pub trait Validator {
 ///
 /// assert_eq!(Required.validate("some-data"), None);
 /// assert_eq!(Required.validate(""), Some("Field is required".to_string()));
+///
+/// // collections require at least one item
+/// assert_eq!(Required.validate_items(&["".to_string()]), None);
+/// assert_eq!(Required.validate_items(&[]), Some("Field is required".to_string()));
 /// ```
 #[derive(Clone, Debug)]
 pub struct Required;
@@ -32,6 +110,13 @@ impl Validator for Required {
             None
         }
     }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        if items.is_empty() {
+            Some("Field is required".to_string())
+        } else {
+            None
+        }
+    }
 }
 
 /// Ensures path is free.
@@ -55,7 +140,7 @@ pub struct PathFree;
 
 impl Validator for PathFree {
     fn validate(&self, data: &str) -> Option<String> {
-        let path = Path::new(data);
+        let path = PathBuf::from(expand_tilde(data));
         if path.exists() {
             Some("Path is already used".to_string())
         } else {
@@ -85,15 +170,11 @@ pub struct DirExists;
 
 impl Validator for DirExists {
     fn validate(&self, data: &str) -> Option<String> {
-        let path = Path::new(data);
-        if path.exists() {
-            if path.metadata().unwrap().is_dir() {
-                None
-            } else {
-                Some("It's not a dir".to_string())
-            }
-        } else {
-            Some("Dir doesn't exist".to_string())
+        match fs::metadata(expand_tilde(data)) {
+            Ok(ref m) if m.is_dir() => None,
+            Ok(_) => Some("It's not a dir".to_string()),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Some("Dir doesn't exist".to_string()),
+            Err(e) => Some(format!("Dir can't be accessed: {}", e)),
         }
     }
 }
@@ -119,15 +200,13 @@ pub struct FileExists;
 
 impl Validator for FileExists {
     fn validate(&self, data: &str) -> Option<String> {
-        let path = Path::new(data);
-        if path.exists() {
-            if path.metadata().unwrap().is_file() {
-                None
-            } else {
-                Some("It's not a file".to_string())
+        match fs::metadata(expand_tilde(data)) {
+            Ok(ref m) if m.is_file() => None,
+            Ok(_) => Some("It's not a file".to_string()),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                Some("File doesn't exist".to_string())
             }
-        } else {
-            Some("File doesn't exist".to_string())
+            Err(e) => Some(format!("File can't be accessed: {}", e)),
         }
     }
 }
@@ -160,15 +239,1301 @@ where
     }
 }
 
+/// Ensures data matches regular expression.
+///
+/// Examples
+///
+/// ```
+/// extern crate fui;
+/// extern crate regex;
+///
+/// use fui::validators::Validator;
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let v = Regex::new("^[a-z]+$").unwrap();
+/// assert_eq!(v.validate("abc"), None);
+/// assert_eq!(v.validate("123"), Some("Value doesn't match pattern: ^[a-z]+$".to_string()));
+/// # }
+/// ```
 impl Validator for Regex {
     fn validate(&self, data: &str) -> Option<String> {
         if self.is_match(data) {
             None
         } else {
-            Some(format!(
-                "Value {:?} does not match: \"{:?}\" regular exp.",
-                data, self
-            ))
+            Some(format!("Value doesn't match pattern: {}", self.as_str()))
+        }
+    }
+}
+
+/// Ensures all validators pass, returns the first error.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{All, FileExists, Required, Validator};
+///
+/// let v = All(vec![Box::new(Required), Box::new(FileExists)]);
+/// assert_eq!(v.validate("./Cargo.toml"), None);
+/// assert_eq!(v.validate(""), Some("Field is required".to_string()));
+/// assert_eq!(v.validate("./missing-file"), Some("File doesn't exist".to_string()));
+/// ```
+///
+/// Combination is a warning only when all its validators are warnings.
+///
+/// ```
+/// use fui::validators::{All, DirEmpty, Required, Severity, Validator, Warn};
+///
+/// let v = All(vec![Box::new(Warn(DirEmpty))]);
+/// assert_eq!(v.severity(), Severity::Warning);
+/// let v = All(vec![Box::new(Warn(DirEmpty)), Box::new(Required)]);
+/// assert_eq!(v.severity(), Severity::Error);
+/// ```
+pub struct All(pub Vec<Box<Validator>>);
+
+impl Validator for All {
+    fn validate(&self, data: &str) -> Option<String> {
+        self.0.iter().filter_map(|v| v.validate(data)).next()
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        self.0.iter().filter_map(|v| v.validate_items(items)).next()
+    }
+    fn severity(&self) -> Severity {
+        combined_severity(&self.0)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.0.iter().any(|v| v.is_pending(data))
+    }
+}
+
+/// Returns `Warning` if all `validators` are warnings, `Error` otherwise.
+fn combined_severity(validators: &[Box<Validator>]) -> Severity {
+    if !validators.is_empty() && validators.iter().all(|v| v.severity() == Severity::Warning) {
+        Severity::Warning
+    } else {
+        Severity::Error
+    }
+}
+
+/// Ensures at least one of validators passes, otherwise returns all errors.
+///
+/// Examples
+///
+/// ```
+/// extern crate fui;
+/// extern crate regex;
+///
+/// use fui::validators::{Any, FileExists, Validator};
+/// use regex::Regex;
+///
+/// # fn main() {
+/// let v = Any(vec![
+///     Box::new(FileExists),
+///     Box::new(Regex::new("^https?://").unwrap()),
+/// ]);
+/// assert_eq!(v.validate("./Cargo.toml"), None);
+/// assert_eq!(v.validate("https://docs.rs"), None);
+/// assert_eq!(
+///     v.validate("./missing-file"),
+///     Some("File doesn't exist or value doesn't match pattern: ^https?://".to_string())
+/// );
+/// # }
+/// ```
+pub struct Any(pub Vec<Box<Validator>>);
+
+impl Validator for Any {
+    fn validate(&self, data: &str) -> Option<String> {
+        self.any(|v| v.validate(data))
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        self.any(|v| v.validate_items(items))
+    }
+    fn severity(&self) -> Severity {
+        combined_severity(&self.0)
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.0.iter().any(|v| v.is_pending(data))
+    }
+}
+
+impl Any {
+    fn any<F: Fn(&Box<Validator>) -> Option<String>>(&self, validate: F) -> Option<String> {
+        let mut errors = Vec::with_capacity(self.0.len());
+        for v in self.0.iter() {
+            match validate(v) {
+                None => return None,
+                Some(e) => errors.push(e),
+            }
+        }
+        if errors.is_empty() {
+            return None;
+        }
+        // only the first letter of joined message stays capitalized
+        let msg = errors
+            .iter()
+            .enumerate()
+            .map(|(idx, e)| if idx == 0 { e.to_owned() } else { lowercase_first(e) })
+            .collect::<Vec<String>>()
+            .join(" or ");
+        Some(msg)
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Ensures validator fails.
+///
+/// Single values and collections are negated separately. Validators of collections (like
+/// `MinItems`) accept every single value, so their negation rejects every single value and
+/// should be used only with fields of collections (like `Multiselect`).
+///
+/// Message of pending check (see `Validator::is_pending`) is kept until the check finishes.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{DirExists, MinItems, Not, Severity, Validator, Warn};
+///
+/// let v = Not(DirExists);
+/// assert_eq!(v.validate("./missing-dir"), None);
+/// assert_eq!(v.validate("./src"), Some("Value is not allowed".to_string()));
+///
+/// let v = Not(MinItems(2));
+/// assert_eq!(v.validate_items(&vec!["a".to_string()]), None);
+/// assert!(v.validate_items(&vec!["a".to_string(), "b".to_string()]).is_some());
+/// // single value always satisfies `MinItems`
+/// assert_eq!(v.validate("a"), Some("Value is not allowed".to_string()));
+///
+/// // severity of negated validator is kept
+/// assert_eq!(Not(Warn(DirExists)).severity(), Severity::Warning);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Not<V>(pub V);
+
+impl<V: Validator> Validator for Not<V> {
+    fn validate(&self, data: &str) -> Option<String> {
+        let result = self.0.validate(data);
+        // message of pending check isn't negated
+        if self.0.is_pending(data) {
+            return result;
+        }
+        match result {
+            Some(_) => None,
+            None => Some("Value is not allowed".to_string()),
+        }
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        let result = self.0.validate_items(items);
+        if items.iter().any(|x| self.0.is_pending(x)) {
+            return result;
+        }
+        match result {
+            Some(_) => None,
+            None => Some("Value is not allowed".to_string()),
+        }
+    }
+    fn severity(&self) -> Severity {
+        self.0.severity()
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.0.is_pending(data)
+    }
+}
+
+/// Replaces error message of validator.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Not, PathFree, Validator, WithMessage};
+///
+/// let v = WithMessage(PathFree, "Pick a new file name");
+/// assert_eq!(v.validate("./free-path"), None);
+/// assert_eq!(v.validate("./"), Some("Pick a new file name".to_string()));
+///
+/// let v = WithMessage(Not(PathFree), "Path must exist");
+/// assert_eq!(v.validate("./free-path"), Some("Path must exist".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct WithMessage<V, M>(pub V, pub M);
+
+impl<V: Validator, M: AsRef<str>> Validator for WithMessage<V, M> {
+    fn validate(&self, data: &str) -> Option<String> {
+        let result = self.0.validate(data);
+        if self.0.is_pending(data) {
+            return result;
+        }
+        result.map(|_| self.1.as_ref().to_string())
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        let result = self.0.validate_items(items);
+        if items.iter().any(|x| self.0.is_pending(x)) {
+            return result;
+        }
+        result.map(|_| self.1.as_ref().to_string())
+    }
+    fn severity(&self) -> Severity {
+        self.0.severity()
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.0.is_pending(data)
+    }
+}
+
+/// Turns messages of validator into warnings, which don't block submitting.
+///
+/// Warnings are shown under the field and listed in a dialog asking for confirmation on submit.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{PathFree, Severity, Validator, Warn, WithMessage};
+///
+/// let v = Warn(WithMessage(PathFree, "File will be overwritten"));
+/// assert_eq!(v.severity(), Severity::Warning);
+/// assert_eq!(v.validate("./Cargo.toml"), Some("File will be overwritten".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Warn<V>(pub V);
+
+impl<V: Validator> Validator for Warn<V> {
+    fn validate(&self, data: &str) -> Option<String> {
+        self.0.validate(data)
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        self.0.validate_items(items)
+    }
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.0.is_pending(data)
+    }
+}
+
+/// Ensures data has at least given number of characters.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MinLength, Validator};
+///
+/// assert_eq!(MinLength(3).validate("abc"), None);
+/// assert_eq!(MinLength(3).validate("ab"), Some("Value must have at least 3 characters".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinLength(pub usize);
+
+impl Validator for MinLength {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.chars().count() < self.0 {
+            Some(format!("Value must have at least {} characters", self.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures data has at most given number of characters.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MaxLength, Validator};
+///
+/// assert_eq!(MaxLength(3).validate("abc"), None);
+/// assert_eq!(MaxLength(3).validate("abcd"), Some("Value must have at most 3 characters".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaxLength(pub usize);
+
+impl Validator for MaxLength {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.chars().count() > self.0 {
+            Some(format!("Value must have at most {} characters", self.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures data is a number from range (inclusive).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Range, Validator};
+///
+/// let v = Range(1, 10);
+/// assert_eq!(v.validate("10"), None);
+/// assert_eq!(v.validate("11"), Some("Value must be between 1 and 10".to_string()));
+/// assert_eq!(v.validate("x"), Some("Value must be a number".to_string()));
+///
+/// let v = Range(0.0, 1.0);
+/// assert_eq!(v.validate("0.5"), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Range<T>(pub T, pub T);
+
+impl<T> Validator for Range<T>
+where
+    T: FromStr + PartialOrd + Display,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        match data.trim().parse::<T>() {
+            Ok(ref v) if *v < self.0 || *v > self.1 => {
+                Some(format!("Value must be between {} and {}", self.0, self.1))
+            }
+            Ok(_) => None,
+            Err(_) => Some("Value must be a number".to_string()),
+        }
+    }
+}
+
+/// Ensures date/time (ISO-8601 value of given kind) is within bounds.
+///
+/// `Date`, `Time` and `DateTime` fields check their `min`/`max` with this validator, it can be
+/// used directly to combine it or to replace its messages.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{DatetimeRange, Validator, WithMessage};
+/// use fui::views::DatetimeKind;
+///
+/// let v = DatetimeRange::new(DatetimeKind::Date).min("2018-01-01").max("2018-12-31");
+/// assert_eq!(v.validate("2018-06-15"), None);
+/// assert_eq!(
+///     v.validate("2017-12-31"),
+///     Some("Value must not be earlier than 2018-01-01".to_string())
+/// );
+/// assert_eq!(v.validate("2018-13-01"), Some("Value is not a valid date or time".to_string()));
+///
+/// let v = WithMessage(v, "Pick a day of 2018");
+/// assert_eq!(v.validate("2019-01-01"), Some("Pick a day of 2018".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DatetimeRange {
+    kind: DatetimeKind,
+    pub(crate) min: Option<Stamp>,
+    pub(crate) max: Option<Stamp>,
+}
+
+impl DatetimeRange {
+    /// Creates a new `DatetimeRange` for values of `kind` without bounds.
+    pub fn new(kind: DatetimeKind) -> Self {
+        DatetimeRange {
+            kind,
+            min: None,
+            max: None,
+        }
+    }
+    /// Sets the lowest allowed value.
+    ///
+    /// # Panics
+    ///
+    /// If `min` isn't a valid value of validator's kind.
+    pub fn min(mut self, min: &str) -> Self {
+        self.min = Some(parse_bound(self.kind, min));
+        self
+    }
+    /// Sets the highest allowed value.
+    ///
+    /// # Panics
+    ///
+    /// If `max` isn't a valid value of validator's kind.
+    pub fn max(mut self, max: &str) -> Self {
+        self.max = Some(parse_bound(self.kind, max));
+        self
+    }
+}
+
+impl Validator for DatetimeRange {
+    fn validate(&self, data: &str) -> Option<String> {
+        let stamp = match Stamp::parse(self.kind, data) {
+            Some(stamp) => stamp,
+            None => return Some("Value is not a valid date or time".to_string()),
+        };
+        match (self.min, self.max) {
+            (Some(min), _) if stamp < min => Some(format!(
+                "Value must not be earlier than {}",
+                min.format(self.kind)
+            )),
+            (_, Some(max)) if stamp > max => Some(format!(
+                "Value must not be later than {}",
+                max.format(self.kind)
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Ensures collection (like `Multiselect`'s selection) has at least given number of items.
+///
+/// Single values are always valid.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MinItems, Validator};
+///
+/// let items = vec!["a".to_string()];
+/// assert_eq!(MinItems(1).validate_items(&items), None);
+/// assert_eq!(MinItems(2).validate_items(&items), Some("Select at least 2 items".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MinItems(pub usize);
+
+impl Validator for MinItems {
+    fn validate(&self, _data: &str) -> Option<String> {
+        None
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        if items.len() < self.0 {
+            Some(format!("Select at least {} items", self.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures collection (like `Multiselect`'s selection) has at most given number of items.
+///
+/// Single values are always valid.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{MaxItems, Validator};
+///
+/// let items = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(MaxItems(2).validate_items(&items), None);
+/// assert_eq!(MaxItems(1).validate_items(&items), Some("Select at most 1 items".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MaxItems(pub usize);
+
+impl Validator for MaxItems {
+    fn validate(&self, _data: &str) -> Option<String> {
+        None
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        if items.len() > self.0 {
+            Some(format!("Select at most {} items", self.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures items of collection (like `Multiselect`'s selection) aren't repeated.
+///
+/// Single values are always valid.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{UniqueItems, Validator};
+///
+/// let items = vec!["a".to_string(), "b".to_string()];
+/// assert_eq!(UniqueItems.validate_items(&items), None);
+/// let items = vec!["a".to_string(), "a".to_string()];
+/// assert_eq!(UniqueItems.validate_items(&items), Some("Item \"a\" is repeated".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct UniqueItems;
+
+impl Validator for UniqueItems {
+    fn validate(&self, _data: &str) -> Option<String> {
+        None
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        let mut seen = HashSet::with_capacity(items.len());
+        items
+            .iter()
+            .find(|x| !seen.insert(*x))
+            .map(|x| format!("Item {:?} is repeated", x))
+    }
+}
+
+// patterns are compiled once, validators run on each submit (and for each item of lists)
+lazy_static! {
+    static ref HOSTNAME_LABEL: Regex =
+        Regex::new("^[a-zA-Z0-9]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?$").unwrap();
+    static ref EMAIL_LOCAL: Regex = Regex::new(r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+$").unwrap();
+    static ref URL: Regex = Regex::new(r"^([a-zA-Z][a-zA-Z0-9+.-]*)://[^\s/?#]+[^\s]*$").unwrap();
+    // see: https://semver.org
+    static ref SEMVER: Regex = Regex::new(concat!(
+        r"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)",
+        r"(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)",
+        r"(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?",
+        r"(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$"
+    )).unwrap();
+    static ref UUID: Regex = Regex::new(
+        "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+    ).unwrap();
+    static ref K8S_NAME: Regex = Regex::new("^[a-z0-9]([-a-z0-9]*[a-z0-9])?$").unwrap();
+    static ref CRATE_NAME: Regex = Regex::new("^[a-zA-Z][a-zA-Z0-9_-]{0,63}$").unwrap();
+}
+
+fn is_hostname(data: &str) -> bool {
+    !data.is_empty() && data.len() <= 253 && data.split('.').all(|x| HOSTNAME_LABEL.is_match(x))
+}
+
+/// Ensures data is an email address.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Email, Validator};
+///
+/// assert_eq!(Email.validate("user@example.com"), None);
+/// assert_eq!(Email.validate("user@"), Some("Value is not a valid email address".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Email;
+
+impl Validator for Email {
+    fn validate(&self, data: &str) -> Option<String> {
+        let mut parts = data.rsplitn(2, '@');
+        let domain = parts.next().unwrap_or("");
+        let user = parts.next().unwrap_or("");
+        if EMAIL_LOCAL.is_match(user) && domain.contains('.') && is_hostname(domain) {
+            None
+        } else {
+            Some("Value is not a valid email address".to_string())
+        }
+    }
+}
+
+/// Ensures data is an URL, optionally with one of allowed schemes.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Url, Validator};
+///
+/// assert_eq!(Url::new().validate("ftp://example.com/file"), None);
+/// assert_eq!(Url::new().validate("example.com"), Some("Value is not a valid URL".to_string()));
+///
+/// let v = Url::new().schemes(vec!["http", "https"]);
+/// assert_eq!(v.validate("https://example.com"), None);
+/// assert_eq!(
+///     v.validate("ftp://example.com"),
+///     Some("URL scheme must be one of: http, https".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Url {
+    schemes: Vec<String>,
+}
+
+impl Url {
+    /// Creates a new `Url` which allows any scheme.
+    pub fn new() -> Self {
+        Url {
+            schemes: Vec::new(),
+        }
+    }
+    /// Limits allowed schemes (like `https`).
+    pub fn schemes<T: Deref<Target = str>>(mut self, schemes: Vec<T>) -> Self {
+        self.schemes = schemes.iter().map(|x| x.to_lowercase()).collect();
+        self
+    }
+}
+
+impl Default for Url {
+    fn default() -> Self {
+        Url::new()
+    }
+}
+
+impl Validator for Url {
+    fn validate(&self, data: &str) -> Option<String> {
+        let scheme = match URL.captures(data).and_then(|c| c.get(1)) {
+            Some(m) => m.as_str().to_lowercase(),
+            None => return Some("Value is not a valid URL".to_string()),
+        };
+        if self.schemes.is_empty() || self.schemes.contains(&scheme) {
+            None
+        } else {
+            Some(format!(
+                "URL scheme must be one of: {}",
+                self.schemes.join(", ")
+            ))
+        }
+    }
+}
+
+/// Ensures data is an IPv4 address.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Ipv4, Validator};
+///
+/// assert_eq!(Ipv4.validate("127.0.0.1"), None);
+/// assert_eq!(Ipv4.validate("256.0.0.1"), Some("Value is not a valid IPv4 address".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv4;
+
+impl Validator for Ipv4 {
+    fn validate(&self, data: &str) -> Option<String> {
+        match data.parse::<Ipv4Addr>() {
+            Ok(_) => None,
+            Err(_) => Some("Value is not a valid IPv4 address".to_string()),
+        }
+    }
+}
+
+/// Ensures data is an IPv6 address.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Ipv6, Validator};
+///
+/// assert_eq!(Ipv6.validate("::1"), None);
+/// assert_eq!(Ipv6.validate("127.0.0.1"), Some("Value is not a valid IPv6 address".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ipv6;
+
+impl Validator for Ipv6 {
+    fn validate(&self, data: &str) -> Option<String> {
+        match data.parse::<Ipv6Addr>() {
+            Ok(_) => None,
+            Err(_) => Some("Value is not a valid IPv6 address".to_string()),
+        }
+    }
+}
+
+/// Ensures data is an IPv4 or IPv6 network in CIDR notation.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Cidr, Validator};
+///
+/// assert_eq!(Cidr.validate("10.0.0.0/8"), None);
+/// assert_eq!(Cidr.validate("fd00::/8"), None);
+/// assert_eq!(Cidr.validate("10.0.0.0/33"), Some("Value is not a valid CIDR".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cidr;
+
+impl Validator for Cidr {
+    fn validate(&self, data: &str) -> Option<String> {
+        let mut parts = data.splitn(2, '/');
+        let addr = parts.next().unwrap_or("");
+        let prefix = parts.next().and_then(|x| x.parse::<u8>().ok());
+        let valid = match (addr.parse::<IpAddr>(), prefix) {
+            (Ok(IpAddr::V4(_)), Some(prefix)) => prefix <= 32,
+            (Ok(IpAddr::V6(_)), Some(prefix)) => prefix <= 128,
+            _ => false,
+        };
+        if valid {
+            None
+        } else {
+            Some("Value is not a valid CIDR".to_string())
+        }
+    }
+}
+
+/// Ensures data is a hostname (RFC 1123).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Hostname, Validator};
+///
+/// assert_eq!(Hostname.validate("db-1.example.com"), None);
+/// assert_eq!(Hostname.validate("-db.example.com"), Some("Value is not a valid hostname".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hostname;
+
+impl Validator for Hostname {
+    fn validate(&self, data: &str) -> Option<String> {
+        if is_hostname(data) {
+            None
+        } else {
+            Some("Value is not a valid hostname".to_string())
+        }
+    }
+}
+
+/// Ensures data is a port number (1-65535).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Port, Validator};
+///
+/// assert_eq!(Port.validate("8080"), None);
+/// assert_eq!(Port.validate("0"), Some("Value is not a valid port number".to_string()));
+/// assert_eq!(Port.validate("65536"), Some("Value is not a valid port number".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Port;
+
+impl Validator for Port {
+    fn validate(&self, data: &str) -> Option<String> {
+        match data.parse::<u16>() {
+            Ok(port) if port > 0 => None,
+            _ => Some("Value is not a valid port number".to_string()),
+        }
+    }
+}
+
+/// Ensures data is a semantic version (like `1.2.3-beta.1+build`).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Semver, Validator};
+///
+/// assert_eq!(Semver.validate("1.2.3-beta.1+build.5"), None);
+/// assert_eq!(Semver.validate("1.2"), Some("Value is not a valid semantic version".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Semver;
+
+impl Validator for Semver {
+    fn validate(&self, data: &str) -> Option<String> {
+        if SEMVER.is_match(data) {
+            None
+        } else {
+            Some("Value is not a valid semantic version".to_string())
+        }
+    }
+}
+
+/// Ensures data is an UUID (like `123e4567-e89b-12d3-a456-426655440000`).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Uuid, Validator};
+///
+/// assert_eq!(Uuid.validate("123e4567-e89b-12d3-a456-426655440000"), None);
+/// assert_eq!(Uuid.validate("123e4567"), Some("Value is not a valid UUID".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Uuid;
+
+impl Validator for Uuid {
+    fn validate(&self, data: &str) -> Option<String> {
+        if UUID.is_match(data) {
+            None
+        } else {
+            Some("Value is not a valid UUID".to_string())
+        }
+    }
+}
+
+/// Ensures data is a Kubernetes name (DNS label, like names of namespaces or services).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{K8sName, Validator};
+///
+/// assert_eq!(K8sName.validate("my-app-1"), None);
+/// assert_eq!(
+///     K8sName.validate("My_App"),
+///     Some("Value must consist of lower case alphanumeric characters or '-'".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct K8sName;
+
+impl Validator for K8sName {
+    fn validate(&self, data: &str) -> Option<String> {
+        if data.len() > 63 {
+            Some("Value must be no more than 63 characters".to_string())
+        } else if !K8S_NAME.is_match(data) {
+            Some("Value must consist of lower case alphanumeric characters or '-'".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures data is a Rust crate name.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{CrateName, Validator};
+///
+/// assert_eq!(CrateName.validate("serde_json"), None);
+/// assert_eq!(CrateName.validate("1st-crate"), Some("Value is not a valid crate name".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CrateName;
+
+impl Validator for CrateName {
+    fn validate(&self, data: &str) -> Option<String> {
+        if CRATE_NAME.is_match(data) {
+            None
+        } else {
+            Some("Value is not a valid crate name".to_string())
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Access {
+    Read,
+    Write,
+    Execute,
+}
+
+#[cfg(unix)]
+fn has_access(path: &Path, access: Access) -> bool {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let mode = match access {
+        Access::Read => libc::R_OK,
+        Access::Write => libc::W_OK,
+        Access::Execute => libc::X_OK,
+    };
+    CString::new(path.as_os_str().as_bytes())
+        .map(|p| unsafe { libc::access(p.as_ptr(), mode) == 0 })
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn has_access(path: &Path, access: Access) -> bool {
+    match (access, path.metadata()) {
+        (Access::Read, Ok(_)) => true,
+        (Access::Write, Ok(m)) => !m.permissions().readonly(),
+        (Access::Execute, Ok(m)) => m.is_dir() || ["exe", "bat", "cmd"]
+            .iter()
+            .any(|ext| path.extension().map(|x| x == *ext).unwrap_or(false)),
+        (_, Err(_)) => false,
+    }
+}
+
+/// Ensures path is an empty dir (or doesn't exist), usually used with `Warn`.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{DirEmpty, Validator};
+///
+/// assert_eq!(DirEmpty.validate("./missing-dir"), None);
+/// assert_eq!(DirEmpty.validate("./src"), Some("Dir is not empty".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DirEmpty;
+
+impl Validator for DirEmpty {
+    fn validate(&self, data: &str) -> Option<String> {
+        match fs::read_dir(expand_tilde(data)) {
+            Ok(mut entries) => entries.next().map(|_| "Dir is not empty".to_string()),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => Some(format!("Dir can't be accessed: {}", e)),
+        }
+    }
+}
+
+/// Ensures path exists and is readable.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Readable, Validator};
+///
+/// assert_eq!(Readable.validate("./Cargo.toml"), None);
+/// assert_eq!(Readable.validate("./missing-file"), Some("Path doesn't exist".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Readable;
+
+impl Validator for Readable {
+    fn validate(&self, data: &str) -> Option<String> {
+        let path = PathBuf::from(expand_tilde(data));
+        if !path.exists() {
+            Some("Path doesn't exist".to_string())
+        } else if !has_access(&path, Access::Read) {
+            Some("Path isn't readable".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// Ensures path is writable, missing path is writable when its parent dir is.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Validator, Writable};
+///
+/// assert_eq!(Writable.validate("./new-file"), None);
+/// assert_eq!(Writable.validate("./missing-dir/new-file"), Some("Path isn't writable".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Writable;
+
+impl Validator for Writable {
+    fn validate(&self, data: &str) -> Option<String> {
+        let path = PathBuf::from(expand_tilde(data));
+        let to_check = if path.exists() {
+            path
+        } else {
+            match path.parent() {
+                Some(p) if p.as_os_str().is_empty() => PathBuf::from("."),
+                Some(p) => p.to_path_buf(),
+                None => path,
+            }
+        };
+        if has_access(&to_check, Access::Write) {
+            None
+        } else {
+            Some("Path isn't writable".to_string())
+        }
+    }
+}
+
+/// Ensures path is an executable file.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Executable, Validator};
+///
+/// # #[cfg(unix)]
+/// assert_eq!(Executable.validate("/bin/sh"), None);
+/// assert_eq!(Executable.validate("./src"), Some("It's not a file".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Executable;
+
+impl Validator for Executable {
+    fn validate(&self, data: &str) -> Option<String> {
+        let path = PathBuf::from(expand_tilde(data));
+        match path.metadata() {
+            Ok(ref m) if !m.is_file() => Some("It's not a file".to_string()),
+            Ok(_) if has_access(&path, Access::Execute) => None,
+            Ok(_) => Some("File isn't executable".to_string()),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                Some("File doesn't exist".to_string())
+            }
+            Err(e) => Some(format!("File can't be accessed: {}", e)),
+        }
+    }
+}
+
+/// Ensures parent dir of path exists (useful for output paths).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{ParentDirExists, Validator};
+///
+/// assert_eq!(ParentDirExists.validate("./src/new-file"), None);
+/// assert_eq!(ParentDirExists.validate("new-file"), None);
+/// assert_eq!(
+///     ParentDirExists.validate("./missing-dir/new-file"),
+///     Some("Parent dir doesn't exist".to_string())
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParentDirExists;
+
+impl Validator for ParentDirExists {
+    fn validate(&self, data: &str) -> Option<String> {
+        let path = PathBuf::from(expand_tilde(data));
+        let parent = match path.parent() {
+            Some(p) if p.as_os_str().is_empty() => Path::new("."),
+            Some(p) => p,
+            None => return None,
+        };
+        if parent.is_dir() {
+            None
+        } else {
+            Some("Parent dir doesn't exist".to_string())
+        }
+    }
+}
+
+/// Ensures path has one of extensions.
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{ExtensionIn, Validator};
+///
+/// let v = ExtensionIn(vec!["tar", "gz"]);
+/// assert_eq!(v.validate("archive.tar.gz"), None);
+/// assert_eq!(v.validate("archive.zip"), Some("Extension must be one of: tar, gz".to_string()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExtensionIn<T>(pub Vec<T>);
+
+impl<T> Validator for ExtensionIn<T>
+where
+    T: Deref<Target = str>,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        let ext = Path::new(data).extension().and_then(|x| x.to_str());
+        match ext {
+            Some(ext) if self.0.iter().any(|x| &**x == ext) => None,
+            _ => {
+                let allowed = self.0.iter().map(|x| &**x).collect::<Vec<&str>>();
+                Some(format!("Extension must be one of: {}", allowed.join(", ")))
+            }
+        }
+    }
+}
+
+/// Ensures path stays inside `base` dir (blocks escapes like `../`).
+///
+/// Examples
+///
+/// ```
+/// use fui::validators::{Validator, WithinDir};
+///
+/// let v = WithinDir("./src");
+/// assert_eq!(v.validate("./src/lib.rs"), None);
+/// assert_eq!(v.validate("./src/new-dir/new-file"), None);
+/// assert!(v.validate("./src/../Cargo.toml").is_some());
+/// ```
+///
+/// Symlinks are resolved, so path through symlink pointing outside of `base` is rejected.
+#[derive(Clone, Debug)]
+pub struct WithinDir<T>(pub T);
+
+impl<T> Validator for WithinDir<T>
+where
+    T: Deref<Target = str>,
+{
+    fn validate(&self, data: &str) -> Option<String> {
+        let base = normalize_path(&self.0);
+        if normalize_path(data).starts_with(&base) {
+            None
+        } else {
+            Some(format!("Path must be inside: {}", &*self.0))
+        }
+    }
+}
+
+/// Message of `Command` validator which didn't finish yet.
+const CHECKING: &str = "Checking…";
+
+/// Ensures external command accepts data (exits with zero status).
+///
+/// Command line is split on whitespaces (there is no shell quoting), argument `{}` (or its part)
+/// is replaced with data, when there is no `{}` data is passed to command's stdin.
+/// Stderr of failed command is used as error message.
+///
+/// Command runs in background thread and it's killed when it doesn't finish before timeout
+/// (2 seconds by default). Until it finishes, validator reports "Checking…" and `FormView`
+/// postpones submitting. Finished check is delivered through Cursive's callback sink (see
+/// `Command::sink`, which requires `Cursive::set_fps`), otherwise it's picked up on next event.
+///
+/// Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use fui::validators::{Command, Validator};
+///
+/// let v = Command::new("grep -q ^[0-9]*$");
+/// assert_eq!(v.check("123"), None);
+/// assert!(v.check("12a").is_some());
+///
+/// // checks run in background
+/// assert_eq!(v.validate("456"), Some("Checking…".to_string()));
+/// while v.is_pending("456") {
+///     std::thread::sleep(Duration::from_millis(10));
+/// }
+/// assert_eq!(v.validate("456"), None);
+///
+/// let v = Command::new("ls {}");
+/// assert_eq!(v.check("Cargo.toml"), None);
+/// assert!(v.check("missing-file").unwrap().contains("missing-file"));
+///
+/// let v = Command::new("sleep {}").timeout(Duration::from_millis(100));
+/// assert_eq!(v.check("1"), Some("Command timed out after 100ms".to_string()));
+/// ```
+#[derive(Clone)]
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    timeout: Duration,
+    sink: Option<Sender<Box<CbFunc>>>,
+    state: Arc<Mutex<CommandState>>,
+}
+
+/// Maximal number of results remembered by `Command` validator.
+const CHECKED_LIMIT: usize = 100;
+
+#[derive(Default)]
+struct CommandState {
+    /// Data being checked.
+    running: HashSet<String>,
+    /// Results of checked data.
+    checked: HashMap<String, Option<String>>,
+    /// Checked data from the oldest, the oldest results are forgotten first.
+    order: VecDeque<String>,
+}
+
+impl CommandState {
+    fn finish(&mut self, data: String, result: Option<String>) {
+        self.running.remove(&data);
+        if self.checked.insert(data.clone(), result).is_none() {
+            self.order.push_back(data);
+        }
+        while self.order.len() > CHECKED_LIMIT {
+            if let Some(oldest) = self.order.pop_front() {
+                self.checked.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl Command {
+    /// Creates a new `Command` validator running `command_line`.
+    pub fn new<IS: Into<String>>(command_line: IS) -> Self {
+        let command_line = command_line.into();
+        let mut parts = command_line.split_whitespace().map(|x| x.to_string());
+        Command {
+            program: parts.next().unwrap_or_default(),
+            args: parts.collect(),
+            timeout: Duration::from_secs(2),
+            sink: None,
+            state: Arc::new(Mutex::new(CommandState::default())),
+        }
+    }
+
+    /// Sets how long command can run before it's killed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets Cursive's callback sink which is notified when check finishes.
+    pub fn sink(mut self, sink: Sender<Box<CbFunc>>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Runs command for `data` and waits for its result (without background thread).
+    pub fn check(&self, data: &str) -> Option<String> {
+        run(&self.program, &self.args, self.timeout, data).err()
+    }
+
+    /// Starts checking `data` in background thread.
+    fn start(&self, data: &str) {
+        let (program, args, timeout) = (self.program.clone(), self.args.clone(), self.timeout);
+        let state = Arc::clone(&self.state);
+        let sink = self.sink.clone();
+        let data = data.to_string();
+        thread::spawn(move || {
+            let result = run(&program, &args, timeout, &data).err();
+            state.lock().unwrap().finish(data, result);
+            if let Some(sink) = sink {
+                let _ = sink.send(Box::new(|c: &mut Cursive| {
+                    // lets views (like `FormView`) pick up the result
+                    c.screen_mut().on_event(Event::Refresh).process(c);
+                }));
+            }
+        });
+    }
+}
+
+fn run(program: &str, args: &[String], timeout: Duration, data: &str) -> Result<(), String> {
+    let use_stdin = !args.iter().any(|x| x.contains("{}"));
+    let args = args.iter()
+        .map(|x| x.replace("{}", data))
+        .collect::<Vec<String>>();
+    let input = if use_stdin { Some(data) } else { None };
+    run_command(program, &args, input, timeout).map(|_| ())
+}
+
+impl Validator for Command {
+    fn validate(&self, data: &str) -> Option<String> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(result) = state.checked.get(data) {
+            return result.clone();
+        }
+        if state.running.insert(data.to_string()) {
+            self.start(data);
+        }
+        Some(CHECKING.to_string())
+    }
+    fn validate_items(&self, items: &[String]) -> Option<String> {
+        // starts checks of all items, not only till the first pending one
+        let results = items.iter().map(|x| self.validate(x)).collect::<Vec<_>>();
+        results.into_iter().flatten().next()
+    }
+    fn is_pending(&self, data: &str) -> bool {
+        self.state.lock().unwrap().running.contains(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_finishes_validation_of_many_items() {
+        let v = Command::new("true");
+        let items = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(v.validate_items(&items), Some(CHECKING.to_string()));
+        wait_for(&v, &items);
+        assert_eq!(v.validate_items(&items), None);
+        // clones share results
+        assert_eq!(v.clone().validate("a"), None);
+    }
+
+    fn wait_for<V: Validator>(v: &V, items: &[String]) {
+        for _ in 0..200 {
+            if !items.iter().any(|x| v.is_pending(x)) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_not_keeps_pending_message() {
+        let items = vec!["a".to_string()];
+        let v = Not(Command::new("true"));
+        assert_eq!(v.validate("a"), Some(CHECKING.to_string()));
+        wait_for(&v, &items);
+        assert_eq!(v.validate("a"), Some("Value is not allowed".to_string()));
+
+        let v = Not(Command::new("false"));
+        assert_eq!(v.validate_items(&items), Some(CHECKING.to_string()));
+        wait_for(&v, &items);
+        assert_eq!(v.validate_items(&items), None);
+
+        let v = WithMessage(Command::new("false"), "Rejected");
+        assert_eq!(v.validate_items(&items), Some(CHECKING.to_string()));
+        wait_for(&v, &items);
+        assert_eq!(v.validate_items(&items), Some("Rejected".to_string()));
+    }
+
+    #[test]
+    fn test_command_forgets_the_oldest_results() {
+        let mut state = CommandState::default();
+        for idx in 0..CHECKED_LIMIT + 1 {
+            state.finish(idx.to_string(), None);
         }
+        assert_eq!(state.checked.len(), CHECKED_LIMIT);
+        assert!(!state.checked.contains_key("0"));
+        assert!(state.checked.contains_key(&CHECKED_LIMIT.to_string()));
     }
 }
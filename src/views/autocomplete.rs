@@ -3,25 +3,37 @@ use std::rc::Rc;
 use cursive::Cursive;
 use cursive::With;
 use cursive::event::{Callback, Event, EventResult, Key};
+use cursive::theme::ColorStyle;
 use cursive::traits::View;
+use cursive::utils::markup::StyledString;
+use cursive::vec::Vec2;
 use cursive::view::ViewWrapper;
-use cursive::views::{EditView, LinearLayout, SelectView};
+use cursive::views::{EditView, LinearLayout, SelectView, TextView};
 
 use feeders::Feeder;
 use super::is_value_from_select;
+use super::suggestions::SuggestionList;
 
-// TODO:: selecting should auto load next/prev items
-// TODO: better performance while typing
+/// Limit of counted suggestions which don't fit on the current page.
+const MORE_LIMIT: usize = 100;
 
 pub type OnSubmit = Option<Rc<Fn(&mut Cursive, Rc<String>)>>;
 
 /// Single selection view with suggestions
+///
+/// Characters of suggestions which matched typed in text are highlighted.
+///
+/// Suggestions are paged, moving selection past the first/last suggestion (or keys
+/// page up/down) loads the previous/next page, number of the next suggestions is shown below.
 pub struct Autocomplete {
     view: LinearLayout,
 
     feeder: Rc<Feeder>,
     shown_count: u8,
     submit_anything: bool,
+    query: String,
+    position: usize,
+    more_count: usize,
 
     on_submit: OnSubmit,
 }
@@ -31,28 +43,29 @@ impl Autocomplete {
     pub fn new<T: Feeder>(feeder: T) -> Autocomplete {
         let shown_count = 5;
 
-        let select = SelectView::<String>::new()
-                .with_all_str(feeder.query("", 0, shown_count).into_iter())
-                //TODO: make fixed height for select equal to shown_count
-                // use cursive::traits::Boxable;
-                //.fixed_height(shown_count)
-                // * using fixed_height converts SelectView to BoxView
-                // * each shown_count update should update size of select (which'd be BoxView)
-                ;
-
+        //TODO: make fixed height for select equal to shown_count
+        // use cursive::traits::Boxable;
+        //.fixed_height(shown_count)
+        // * using fixed_height converts SelectView to BoxView
+        // * each shown_count update should update size of select (which'd be BoxView)
         let layout = LinearLayout::vertical()
             .child(EditView::new())
-            .child(select);
+            .child(SuggestionList::new())
+            .child(TextView::new(""));
 
-        let ac = Autocomplete {
+        let mut ac = Autocomplete {
             view: layout,
 
             feeder: Rc::new(feeder),
             shown_count: shown_count as u8,
             submit_anything: false,
+            query: String::new(),
+            position: 0,
+            more_count: 0,
 
             on_submit: None,
         };
+        ac.load_page(0);
 
         ac
     }
@@ -77,12 +90,101 @@ impl Autocomplete {
 
     /// Refresh suggestions
     fn refresh_listing(&mut self) {
+        self.query = (*self.get_edit_view().get_content()).clone();
+        self.load_page(0);
+    }
+
+    /// Checks if feeder has new results (like from background query).
+    pub(crate) fn is_feeder_updated(&self) -> bool {
+        self.feeder.is_updated()
+    }
+
+    /// Reloads suggestions when feeder has new results (like from background query).
+    pub(crate) fn apply_feeder_updates(&mut self) {
+        if self.feeder.is_updated() {
+            let position = self.position;
+            self.load_page(position);
+        }
+    }
+
+    /// Shows suggestions for `query` starting from `position`.
+    fn load_page(&mut self, position: usize) {
         let feeder = Rc::clone(&self.feeder);
-        let text = self.get_edit_view().get_content();
         let shown_count = self.shown_count as usize;
-        let select = self.get_select_view_mut();
-        select.clear();
-        select.add_all_str((*feeder).query(text.as_ref(), 0, shown_count).into_iter());
+        // the page together with suggestions after it (to count them)
+        let mut suggestions =
+            feeder.suggest(&self.query, position as isize, shown_count + MORE_LIMIT);
+        let more = suggestions.split_off(shown_count.min(suggestions.len()));
+        self.get_suggestion_list_mut().set_suggestions(suggestions);
+        self.position = position;
+        self.more_count = more.len().min(MORE_LIMIT);
+        let more = match self.more_count {
+            0 => String::new(),
+            MORE_LIMIT => format!("{}+ more", MORE_LIMIT),
+            count => format!("{} more", count),
+        };
+        self.view
+            .get_child_mut(2)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<TextView>()
+            .unwrap()
+            .set_content(StyledString::styled(more, ColorStyle::secondary()));
+    }
+
+    /// Loads the next page, returns `false` if there is no next page.
+    fn next_page(&mut self) -> bool {
+        if self.more_count == 0 {
+            return false;
+        }
+        let position = self.position + self.shown_count as usize;
+        self.load_page(position);
+        true
+    }
+
+    /// Loads the previous page, returns `false` if there is no previous page.
+    fn prev_page(&mut self) -> bool {
+        if self.position == 0 {
+            return false;
+        }
+        let position = self.position.saturating_sub(self.shown_count as usize);
+        self.load_page(position);
+        true
+    }
+
+    /// Checks if the first (`last` = false) or the last suggestion is selected.
+    fn is_edge_selected(&self, last: bool) -> bool {
+        let select = self.get_select_view();
+        match select.selected_id() {
+            Some(idx) if last => idx + 1 == select.len(),
+            Some(idx) => idx == 0,
+            None => false,
+        }
+    }
+
+    /// Moves selection by one suggestion loading other page when needed.
+    ///
+    /// Returns `false` if selection can't be moved to other page.
+    fn move_selection(&mut self, down: bool) -> bool {
+        if !self.is_edge_selected(down) {
+            let select = self.get_select_view_mut();
+            if down {
+                select.select_down(1);
+            } else {
+                select.select_up(1);
+            }
+            return true;
+        }
+        if down && self.next_page() {
+            self.get_select_view_mut().set_selection(0);
+            true
+        } else if !down && self.prev_page() {
+            let last = self.get_select_view().len().saturating_sub(1);
+            self.get_select_view_mut().set_selection(last);
+            true
+        } else {
+            false
+        }
     }
 
     /// Copy selected text to edit view
@@ -115,22 +217,27 @@ impl Autocomplete {
             .unwrap()
     }
 
-    fn get_select_view(&self) -> &SelectView {
+    fn get_suggestion_list_mut(&mut self) -> &mut SuggestionList {
         self.view
-            .get_child(1)
+            .get_child_mut(1)
             .unwrap()
-            .as_any()
-            .downcast_ref::<SelectView<String>>()
+            .as_any_mut()
+            .downcast_mut::<SuggestionList>()
             .unwrap()
     }
 
-    fn get_select_view_mut(&mut self) -> &mut SelectView {
+    pub(crate) fn get_select_view(&self) -> &SelectView {
         self.view
-            .get_child_mut(1)
+            .get_child(1)
             .unwrap()
-            .as_any_mut()
-            .downcast_mut::<SelectView<String>>()
+            .as_any()
+            .downcast_ref::<SuggestionList>()
             .unwrap()
+            .get_select()
+    }
+
+    fn get_select_view_mut(&mut self) -> &mut SelectView {
+        self.get_suggestion_list_mut().get_select_mut()
     }
 
     /// Sets the function to be called when submit is triggered.
@@ -155,6 +262,20 @@ impl Autocomplete {
 impl ViewWrapper for Autocomplete {
     wrap_impl!(self.view: LinearLayout);
 
+    fn wrap_needs_relayout(&self) -> bool {
+        self.is_feeder_updated() || self.view.needs_relayout()
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        self.apply_feeder_updates();
+        self.view.required_size(req)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.apply_feeder_updates();
+        self.view.layout(size);
+    }
+
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         match event {
             Event::Char(_) | Event::Key(Key::Backspace) | Event::Key(Key::Del) => {
@@ -170,27 +291,36 @@ impl ViewWrapper for Autocomplete {
                 EventResult::Consumed(None)
             }
             Event::Key(Key::Down) | Event::Key(Key::Up) => {
-                // handle up/down selection
-                self.with_view_mut(|v| v.on_event(event))
-                    .unwrap_or(EventResult::Ignored);
+                // handle up/down selection, crossing edge of suggestions loads other page
+                let down = event == Event::Key(Key::Down);
+                let in_select = self.view.get_focus_index() == 1;
+                if !(in_select && self.is_edge_selected(down) && self.move_selection(down)) {
+                    self.with_view_mut(|v| v.on_event(event))
+                        .unwrap_or(EventResult::Ignored);
+                }
                 self.selection_to_edit();
                 EventResult::Consumed(None)
             }
+            Event::Key(Key::PageDown) | Event::Key(Key::PageUp) => {
+                let changed = if event == Event::Key(Key::PageDown) {
+                    self.next_page()
+                } else {
+                    self.prev_page()
+                };
+                if changed && self.view.get_focus_index() == 1 {
+                    self.selection_to_edit();
+                }
+                EventResult::Consumed(None)
+            }
             Event::CtrlChar('p') => {
                 // move selection up
-                {
-                    let select = self.get_select_view_mut();
-                    select.select_up(1);
-                }
+                self.move_selection(false);
                 self.selection_to_edit();
                 EventResult::Consumed(None)
             }
             Event::CtrlChar('n') => {
                 // move selection down
-                {
-                    let select = self.get_select_view_mut();
-                    select.select_down(1);
-                }
+                self.move_selection(true);
                 self.selection_to_edit();
                 EventResult::Consumed(None)
             }
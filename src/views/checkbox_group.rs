@@ -0,0 +1,115 @@
+use cursive::event::{Event, EventResult};
+use cursive::traits::View;
+use cursive::view::ViewWrapper;
+use cursive::views::{Checkbox, LinearLayout, TextView};
+
+/// Group of checkboxes over fixed list of options.
+///
+/// Key `ctrl+a` selects all options, `ctrl+n` deselects all.
+pub struct CheckboxGroup {
+    view: LinearLayout,
+
+    options: Vec<String>,
+}
+
+impl CheckboxGroup {
+    /// Creates a new `CheckboxGroup` with passed `options`
+    pub fn new<IS: Into<String>>(options: Vec<IS>) -> Self {
+        let options = options
+            .into_iter()
+            .map(|x| x.into())
+            .collect::<Vec<String>>();
+        let mut layout = LinearLayout::vertical();
+        for option in options.iter() {
+            layout.add_child(
+                LinearLayout::horizontal()
+                    .child(Checkbox::new())
+                    .child(TextView::new(format!(" {}", option))),
+            );
+        }
+        CheckboxGroup {
+            view: layout,
+            options,
+        }
+    }
+
+    fn get_checkbox_mut(&mut self, idx: usize) -> &mut Checkbox {
+        self.view
+            .get_child_mut(idx)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap()
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Checkbox>()
+            .unwrap()
+    }
+
+    fn is_checked(&self, idx: usize) -> bool {
+        self.view
+            .get_child(idx)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+            .get_child(0)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Checkbox>()
+            .unwrap()
+            .is_checked()
+    }
+
+    /// Makes `items` selected, other options are deselected
+    pub fn select_items<IS: AsRef<str>>(&mut self, items: &[IS]) {
+        for idx in 0..self.options.len() {
+            let checked = items.iter().any(|x| x.as_ref() == self.options[idx]);
+            self.get_checkbox_mut(idx).set_checked(checked);
+        }
+    }
+
+    /// Makes all options selected
+    pub fn select_all(&mut self) {
+        for idx in 0..self.options.len() {
+            self.get_checkbox_mut(idx).check();
+        }
+    }
+
+    /// Makes all options deselected
+    pub fn select_none(&mut self) {
+        for idx in 0..self.options.len() {
+            self.get_checkbox_mut(idx).uncheck();
+        }
+    }
+
+    /// Returns selected options (in order of options)
+    pub fn get_selected_items(&self) -> Vec<&String> {
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|&(idx, _)| self.is_checked(idx))
+            .map(|(_, option)| option)
+            .collect()
+    }
+}
+
+impl ViewWrapper for CheckboxGroup {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::CtrlChar('a') => {
+                self.select_all();
+                EventResult::Consumed(None)
+            }
+            Event::CtrlChar('n') => {
+                self.select_none();
+                EventResult::Consumed(None)
+            }
+            _ => self.with_view_mut(|v| v.on_event(event))
+                .unwrap_or(EventResult::Ignored),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::cmp::{max, min};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cursive::Printer;
+use cursive::direction::Direction;
+use cursive::event::{Event, EventResult, Key};
+use cursive::theme::ColorStyle;
+use cursive::vec::Vec2;
+use cursive::view::View;
+
+/// Kind of value edited by `Datetime` view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DatetimeKind {
+    /// Date formatted as `YYYY-MM-DD`
+    Date,
+    /// Time formatted as `HH:MM:SS`
+    Time,
+    /// Date and time formatted as `YYYY-MM-DDTHH:MM:SS`
+    DateTime,
+}
+
+impl DatetimeKind {
+    fn segments(&self) -> &'static [usize] {
+        match *self {
+            DatetimeKind::Date => &[0, 1, 2],
+            DatetimeKind::Time => &[3, 4, 5],
+            DatetimeKind::DateTime => &[0, 1, 2, 3, 4, 5],
+        }
+    }
+}
+
+/// Point in time edited by `Datetime`, ordered chronologically.
+// year, month, day, hour, minute, second (field order gives chronological ordering)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Stamp([i64; 6]);
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl Stamp {
+    /// Current UTC time.
+    fn now() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        // civil-from-days, see http://howardhinnant.github.io/date_algorithms.html
+        let z = secs.div_euclid(86_400) + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        let rem = secs.rem_euclid(86_400);
+        Stamp([year, month, day, rem / 3600, rem % 3600 / 60, rem % 60])
+    }
+
+    /// Parses ISO-8601 `text` of given `kind`, returns `None` for invalid `text`.
+    pub fn parse(kind: DatetimeKind, text: &str) -> Option<Self> {
+        let mut parts = [1970, 1, 1, 0, 0, 0];
+        let (date, time) = match kind {
+            DatetimeKind::Date => (Some(text), None),
+            DatetimeKind::Time => (None, Some(text)),
+            DatetimeKind::DateTime => {
+                let mut split = text.splitn(2, 'T');
+                (split.next(), Some(split.next()?))
+            }
+        };
+        let mut read = |text: &str, sep: char, offset: usize, widths: [usize; 3]| -> Option<()> {
+            let items = text.split(sep).collect::<Vec<&str>>();
+            if items.len() != 3 {
+                return None;
+            }
+            for (idx, item) in items.iter().enumerate() {
+                if item.len() != widths[idx] || !item.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                parts[offset + idx] = item.parse().ok()?;
+            }
+            Some(())
+        };
+        if let Some(date) = date {
+            read(date, '-', 0, [4, 2, 2])?;
+        }
+        if let Some(time) = time {
+            read(time, ':', 3, [2, 2, 2])?;
+        }
+        let stamp = Stamp(parts);
+        if stamp.is_valid() {
+            Some(stamp)
+        } else {
+            None
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        let [year, month, day, hour, minute, second] = self.0;
+        year >= 1
+            && (1..=12).contains(&month)
+            && day >= 1 && day <= days_in_month(year, month)
+            && (0..24).contains(&hour)
+            && (0..60).contains(&minute)
+            && (0..60).contains(&second)
+    }
+
+    /// Formats stamp as ISO-8601 string of given `kind`.
+    pub fn format(&self, kind: DatetimeKind) -> String {
+        let [year, month, day, hour, minute, second] = self.0;
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        let time = format!("{:02}:{:02}:{:02}", hour, minute, second);
+        match kind {
+            DatetimeKind::Date => date,
+            DatetimeKind::Time => time,
+            DatetimeKind::DateTime => format!("{}T{}", date, time),
+        }
+    }
+
+    /// Changes `segment` by `delta`, wrapping inside segment's range (year isn't wrapped).
+    fn shift(&mut self, segment: usize, delta: i64) {
+        let (low, high) = match segment {
+            0 => {
+                self.0[0] = (self.0[0] + delta).clamp(1, 9999);
+                (1, 9999)
+            }
+            1 => (1, 12),
+            2 => (1, days_in_month(self.0[0], self.0[1])),
+            3 => (0, 23),
+            _ => (0, 59),
+        };
+        if segment != 0 {
+            let span = high - low + 1;
+            self.0[segment] = (self.0[segment] - low + delta).rem_euclid(span) + low;
+        }
+        self.0[2] = min(self.0[2], days_in_month(self.0[0], self.0[1]));
+    }
+}
+
+/// Parses bound of allowed values, panics on invalid `text`.
+pub(crate) fn parse_bound(kind: DatetimeKind, text: &str) -> Stamp {
+    match Stamp::parse(kind, text) {
+        Some(stamp) => stamp,
+        None => panic!("Invalid {:?} bound: {:?}", kind, text),
+    }
+}
+
+/// Segmented date/time editor.
+///
+/// Keys left/right pick segment (like day, month, year), keys up/down change it.
+pub struct Datetime {
+    kind: DatetimeKind,
+    value: Stamp,
+    min: Option<Stamp>,
+    max: Option<Stamp>,
+    segment: usize,
+}
+
+impl Datetime {
+    /// Creates a new `Datetime` of given `kind` set to current (UTC) time.
+    pub fn new(kind: DatetimeKind) -> Self {
+        let mut value = Stamp::now();
+        if kind == DatetimeKind::Time {
+            value = Stamp([1970, 1, 1, value.0[3], value.0[4], value.0[5]]);
+        }
+        Datetime {
+            kind,
+            value,
+            min: None,
+            max: None,
+            segment: 0,
+        }
+    }
+
+    /// Checks if `text` is a valid ISO-8601 value of given `kind`.
+    pub fn is_valid(kind: DatetimeKind, text: &str) -> bool {
+        Stamp::parse(kind, text).is_some()
+    }
+
+    /// Sets value from ISO-8601 `text`, invalid `text` is ignored.
+    pub fn set_value(&mut self, text: &str) {
+        if let Some(stamp) = Stamp::parse(self.kind, text) {
+            self.value = stamp;
+            self.clamp();
+        }
+    }
+
+    /// Sets value from ISO-8601 `text`, invalid `text` is ignored.
+    ///
+    /// Chainable variant.
+    pub fn value(mut self, text: &str) -> Self {
+        self.set_value(text);
+        self
+    }
+
+    /// Gets value as ISO-8601 string.
+    pub fn get_value(&self) -> String {
+        self.value.format(self.kind)
+    }
+
+    /// Sets the lowest allowed value.
+    ///
+    /// # Panics
+    ///
+    /// If `text` isn't a valid ISO-8601 value of view's kind.
+    pub fn min(mut self, text: &str) -> Self {
+        self.min = Some(parse_bound(self.kind, text));
+        self.clamp();
+        self
+    }
+
+    /// Sets the highest allowed value.
+    ///
+    /// # Panics
+    ///
+    /// If `text` isn't a valid ISO-8601 value of view's kind.
+    pub fn max(mut self, text: &str) -> Self {
+        self.max = Some(parse_bound(self.kind, text));
+        self.clamp();
+        self
+    }
+
+    fn clamp(&mut self) {
+        if let Some(low) = self.min {
+            self.value = max(self.value, low);
+        }
+        if let Some(high) = self.max {
+            self.value = min(self.value, high);
+        }
+    }
+
+    fn change(&mut self, delta: i64) -> EventResult {
+        let segment = self.kind.segments()[self.segment];
+        self.value.shift(segment, delta);
+        self.clamp();
+        EventResult::Consumed(None)
+    }
+
+    /// Returns (offset, width) of segments as drawn.
+    fn layout(&self) -> Vec<(usize, usize)> {
+        self.kind
+            .segments()
+            .iter()
+            .map(|&s| match s {
+                0 => (0, 4),
+                1 => (5, 2),
+                2 => (8, 2),
+                _ => {
+                    let start = if self.kind == DatetimeKind::Time { 0 } else { 11 };
+                    (start + (s - 3) * 3, 2)
+                }
+            })
+            .collect()
+    }
+}
+
+impl View for Datetime {
+    fn draw(&self, printer: &Printer) {
+        let text = self.get_value();
+        printer.print((0, 0), &text);
+        let (offset, width) = self.layout()[self.segment];
+        let color = if printer.focused {
+            ColorStyle::highlight()
+        } else {
+            ColorStyle::highlight_inactive()
+        };
+        printer.with_color(color, |printer| {
+            printer.print((offset, 0), &text[offset..offset + width]);
+        });
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(self.get_value().len(), 1)
+    }
+
+    fn take_focus(&mut self, _: Direction) -> bool {
+        true
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) if self.segment > 0 => {
+                self.segment -= 1;
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Right) if self.segment + 1 < self.kind.segments().len() => {
+                self.segment += 1;
+                EventResult::Consumed(None)
+            }
+            Event::Key(Key::Up) | Event::Char('+') => self.change(1),
+            Event::Key(Key::Down) | Event::Char('-') => self.change(-1),
+            Event::Key(Key::PageUp) => self.change(10),
+            Event::Key(Key::PageDown) => self.change(-10),
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_checks_format_and_calendar() {
+        let date = DatetimeKind::Date;
+        assert_eq!(Stamp::parse(date, "2018-01-05"), Some(Stamp([2018, 1, 5, 0, 0, 0])));
+        assert_eq!(Stamp::parse(date, "2018-1-5"), None);
+        assert_eq!(Stamp::parse(date, "18-01-05"), None);
+        assert_eq!(Stamp::parse(date, "2018-13-01"), None);
+        assert_eq!(Stamp::parse(date, "2018-02-29"), None);
+        assert_eq!(Stamp::parse(date, "2016-02-29"), Some(Stamp([2016, 2, 29, 0, 0, 0])));
+        assert_eq!(Stamp::parse(date, "1900-02-29"), None);
+        assert_eq!(Stamp::parse(date, "2000-02-29"), Some(Stamp([2000, 2, 29, 0, 0, 0])));
+        assert_eq!(Stamp::parse(date, "0000-01-01"), None);
+
+        let time = DatetimeKind::Time;
+        assert_eq!(Stamp::parse(time, "23:59:59"), Some(Stamp([1970, 1, 1, 23, 59, 59])));
+        assert_eq!(Stamp::parse(time, "24:00:00"), None);
+        assert_eq!(Stamp::parse(time, "12:60:00"), None);
+        assert_eq!(Stamp::parse(time, "1:00:00"), None);
+
+        let date_time = DatetimeKind::DateTime;
+        assert_eq!(
+            Stamp::parse(date_time, "2018-01-05T10:20:30"),
+            Some(Stamp([2018, 1, 5, 10, 20, 30]))
+        );
+        assert_eq!(Stamp::parse(date_time, "2018-01-05"), None);
+        assert_eq!(Stamp::parse(date_time, "2018-01-05 10:20:30"), None);
+    }
+
+    #[test]
+    fn test_format_pads_fields() {
+        let stamp = Stamp([987, 1, 5, 3, 4, 5]);
+        assert_eq!(stamp.format(DatetimeKind::Date), "0987-01-05");
+        assert_eq!(stamp.format(DatetimeKind::Time), "03:04:05");
+        assert_eq!(stamp.format(DatetimeKind::DateTime), "0987-01-05T03:04:05");
+    }
+
+    #[test]
+    fn test_shift_wraps_segments() {
+        let mut stamp = Stamp([2018, 12, 31, 23, 59, 59]);
+        stamp.shift(1, 1);
+        assert_eq!(stamp, Stamp([2018, 1, 31, 23, 59, 59]));
+        stamp.shift(3, 1);
+        stamp.shift(4, 1);
+        stamp.shift(5, 1);
+        assert_eq!(stamp, Stamp([2018, 1, 31, 0, 0, 0]));
+        stamp.shift(5, -1);
+        assert_eq!(stamp, Stamp([2018, 1, 31, 0, 0, 59]));
+        stamp.shift(2, 1);
+        assert_eq!(stamp, Stamp([2018, 1, 1, 0, 0, 59]));
+        stamp.shift(2, -1);
+        assert_eq!(stamp.0[2], 31);
+    }
+
+    #[test]
+    fn test_shift_keeps_day_in_month() {
+        let mut stamp = Stamp([2016, 1, 31, 0, 0, 0]);
+        stamp.shift(1, 1);
+        assert_eq!(stamp, Stamp([2016, 2, 29, 0, 0, 0]));
+        stamp.shift(0, 1);
+        assert_eq!(stamp, Stamp([2017, 2, 28, 0, 0, 0]));
+        stamp.shift(2, 1);
+        assert_eq!(stamp, Stamp([2017, 2, 1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_shift_limits_year() {
+        let mut stamp = Stamp([1, 1, 1, 0, 0, 0]);
+        stamp.shift(0, -10);
+        assert_eq!(stamp.0[0], 1);
+        let mut stamp = Stamp([9995, 1, 1, 0, 0, 0]);
+        stamp.shift(0, 10);
+        assert_eq!(stamp.0[0], 9999);
+    }
+
+    #[test]
+    fn test_clamp_keeps_value_in_bounds() {
+        let view = Datetime::new(DatetimeKind::Date)
+            .min("2018-01-01")
+            .max("2018-12-31")
+            .value("2017-06-15");
+        assert_eq!(view.get_value(), "2018-01-01");
+
+        let mut view = view.value("2019-06-15");
+        assert_eq!(view.get_value(), "2018-12-31");
+        view.change(1);
+        assert_eq!(view.get_value(), "2018-12-31");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid Date bound")]
+    fn test_invalid_bound_panics() {
+        Datetime::new(DatetimeKind::Date).min("2018-1-5");
+    }
+}
@@ -0,0 +1,284 @@
+use std::rc::Rc;
+
+use cursive::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use cursive::traits::{Boxable, View};
+use cursive::view::{AnyView, ViewWrapper};
+use cursive::views::{BoxView, Button, DummyView, LinearLayout};
+
+type BuildRow = Rc<Fn(&str) -> Box<AnyView>>;
+type ReadRow = Rc<Fn(&AnyView) -> String>;
+
+/// Editable list of rows where each row is a view built by `build_row`.
+///
+/// Button `Add` appends a new row, button `Remove` (next to each row) removes it.
+pub struct List {
+    view: LinearLayout,
+
+    build_row: BuildRow,
+    read_row: ReadRow,
+    new_row: String,
+    max_rows: Option<usize>,
+}
+
+impl List {
+    /// Creates a new `List`.
+    ///
+    /// * `build_row` builds row's view for given value
+    /// * `read_row` reads value from row's view
+    /// * `new_row` is value of rows added with button `Add`
+    pub fn new<B, R, IS>(build_row: B, read_row: R, new_row: IS) -> Self
+    where
+        B: Fn(&str) -> Box<AnyView> + 'static,
+        R: Fn(&AnyView) -> String + 'static,
+        IS: Into<String>,
+    {
+        let mut list = List {
+            view: LinearLayout::vertical(),
+
+            build_row: Rc::new(build_row),
+            read_row: Rc::new(read_row),
+            new_row: new_row.into(),
+            max_rows: None,
+        };
+        list.set_values(Vec::new());
+        list
+    }
+
+    /// Limits number of rows which can be added.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Sets rows values.
+    ///
+    /// Chainable variant.
+    pub fn values<IS: Into<String>>(mut self, values: Vec<IS>) -> Self {
+        self.set_values(values.into_iter().map(|x| x.into()).collect());
+        self
+    }
+
+    /// Replaces all rows with rows having `values`.
+    pub fn set_values(&mut self, values: Vec<String>) {
+        let mut rows = LinearLayout::vertical();
+        for value in values.iter() {
+            rows.add_child(self.make_row(value));
+        }
+        self.view = LinearLayout::vertical()
+            .child(rows)
+            .child(Button::new("Add", |_| {}));
+    }
+
+    /// Gets values of all rows.
+    pub fn get_values(&self) -> Vec<String> {
+        let rows = self.get_rows();
+        (0..self.rows_count())
+            .map(|idx| {
+                let row = rows.get_child(idx)
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<LinearLayout>()
+                    .unwrap();
+                let boxed_row = row.get_child(0)
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<BoxView<Box<AnyView>>>()
+                    .unwrap();
+                (self.read_row)(boxed_row.get_inner())
+            })
+            .collect()
+    }
+
+    /// Calls `f` with view of each row.
+    pub(crate) fn for_each_row<F: FnMut(&mut AnyView)>(&mut self, mut f: F) {
+        let count = self.rows_count();
+        let rows = self.get_rows_mut();
+        for idx in 0..count {
+            let boxed_row = rows.get_child_mut(idx)
+                .unwrap()
+                .as_any_mut()
+                .downcast_mut::<LinearLayout>()
+                .unwrap()
+                .get_child_mut(0)
+                .unwrap()
+                .as_any_mut()
+                .downcast_mut::<BoxView<Box<AnyView>>>()
+                .unwrap();
+            f(boxed_row.get_inner_mut());
+        }
+    }
+
+    fn get_rows(&self) -> &LinearLayout {
+        self.view
+            .get_child(0)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<LinearLayout>()
+            .unwrap()
+    }
+
+    fn get_rows_mut(&mut self) -> &mut LinearLayout {
+        self.view
+            .get_child_mut(0)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<LinearLayout>()
+            .unwrap()
+    }
+
+    fn rows_count(&self) -> usize {
+        let rows = self.get_rows();
+        let mut count = 0;
+        while rows.get_child(count).is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    fn make_row(&self, value: &str) -> LinearLayout {
+        LinearLayout::horizontal()
+            .child((self.build_row)(value).full_width())
+            .child(DummyView)
+            .child(Button::new("Remove", |_| {}))
+    }
+
+    fn add_row(&mut self) {
+        let count = self.rows_count();
+        if self.max_rows.map(|max| count >= max).unwrap_or(false) {
+            return;
+        }
+        let row = self.make_row(&self.new_row);
+        self.get_rows_mut().add_child(row);
+    }
+
+    fn remove_row(&mut self, idx: usize) {
+        let mut values = self.get_values();
+        values.remove(idx);
+        self.set_values(values);
+    }
+
+    /// Handles pressing buttons, returns `None` if no button was focused.
+    fn press_focused(&mut self) -> Option<EventResult> {
+        if self.view.get_focus_index() == 1 {
+            self.add_row();
+            return Some(EventResult::Consumed(None));
+        }
+        let rows = self.get_rows();
+        let focused = rows.get_focus_index();
+        let remove_focused = rows.get_child(focused)
+            .and_then(|row| row.as_any().downcast_ref::<LinearLayout>())
+            .map(|row| row.get_focus_index() == 2)
+            .unwrap_or(false);
+        if remove_focused {
+            self.remove_row(focused);
+            return Some(EventResult::Consumed(None));
+        }
+        None
+    }
+}
+
+impl ViewWrapper for List {
+    wrap_impl!(self.view: LinearLayout);
+
+    fn wrap_on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Enter) => match self.press_focused() {
+                Some(result) => result,
+                None => self.with_view_mut(|v| v.on_event(event))
+                    .unwrap_or(EventResult::Ignored),
+            },
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => {
+                let result = self.with_view_mut(|v| v.on_event(event))
+                    .unwrap_or(EventResult::Ignored);
+                match result {
+                    // focused button was released within its area
+                    EventResult::Consumed(Some(_)) => self.press_focused().unwrap_or(result),
+                    _ => result,
+                }
+            }
+            _ => self.with_view_mut(|v| v.on_event(event))
+                .unwrap_or(EventResult::Ignored),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cursive::vec::Vec2;
+    use cursive::views::EditView;
+
+    fn list(values: Vec<&str>) -> List {
+        let mut list = List::new(
+            |value| Box::new(EditView::new().content(value)) as Box<AnyView>,
+            |view| {
+                let edit = view.as_any()
+                    .downcast_ref::<Box<AnyView>>()
+                    .and_then(|x| (**x).as_any().downcast_ref::<EditView>())
+                    .unwrap();
+                (*edit.get_content()).clone()
+            },
+            "",
+        ).values(values);
+        let size = Vec2::new(30, 5);
+        list.required_size(size);
+        list.layout(size);
+        list
+    }
+
+    fn click(list: &mut List, x: usize, y: usize) {
+        let events = vec![
+            MouseEvent::Press(MouseButton::Left),
+            MouseEvent::Release(MouseButton::Left),
+        ];
+        for event in events {
+            list.on_event(Event::Mouse {
+                offset: Vec2::zero(),
+                position: Vec2::new(x, y),
+                event,
+            });
+        }
+    }
+
+    #[test]
+    fn test_click_on_remove_removes_row() {
+        let mut list = list(vec!["a", "b"]);
+        click(&mut list, 25, 0);
+        assert_eq!(list.get_values(), vec!["b"]);
+    }
+
+    #[test]
+    fn test_click_beside_focused_button_does_nothing() {
+        let mut list = list(vec!["a", "b"]);
+        list.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(25, 1),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        // spacer between row's view and focused "Remove"
+        click(&mut list, 21, 1);
+        assert_eq!(list.get_values(), vec!["a", "b"]);
+        // release outside of the pressed button
+        list.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(25, 1),
+            event: MouseEvent::Press(MouseButton::Left),
+        });
+        list.on_event(Event::Mouse {
+            offset: Vec2::zero(),
+            position: Vec2::new(25, 4),
+            event: MouseEvent::Release(MouseButton::Left),
+        });
+        assert_eq!(list.get_values(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_click_on_add_adds_row() {
+        let mut list = list(vec!["a"]);
+        click(&mut list, 1, 1);
+        assert_eq!(list.get_values(), vec!["a", ""]);
+    }
+}
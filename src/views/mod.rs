@@ -1,9 +1,19 @@
 //! Contains views which are building blocks for `fields`.
 mod autocomplete;
+mod checkbox_group;
+mod datetime;
+mod list;
 mod multiselect;
+mod slider;
+mod suggestions;
 
 pub use self::autocomplete::Autocomplete;
+pub use self::checkbox_group::CheckboxGroup;
+pub use self::datetime::{Datetime, DatetimeKind, Stamp};
+pub(crate) use self::datetime::parse_bound;
+pub use self::list::List;
 pub use self::multiselect::Multiselect;
+pub use self::slider::Slider;
 
 use cursive::views::SelectView;
 
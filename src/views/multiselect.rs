@@ -4,6 +4,7 @@ use cursive::Cursive;
 use cursive::With;
 use cursive::event::{Callback, Event, EventResult, Key};
 use cursive::traits::{Boxable, View};
+use cursive::vec::Vec2;
 use cursive::view::ViewWrapper;
 use cursive::views::{BoxView, DummyView, LinearLayout, OnEventView, Panel, SelectView};
 
@@ -70,6 +71,16 @@ impl Multiselect {
         box_view.get_inner().get_inner()
     }
 
+    fn get_options_view_mut(&mut self) -> &mut Autocomplete {
+        let box_view = self.view
+            .get_child_mut(self.options_idx as usize)
+            .unwrap()
+            .as_any_mut()
+            .downcast_mut::<Panel<BoxView<Autocomplete>>>()
+            .unwrap();
+        box_view.get_inner_mut().get_inner_mut()
+    }
+
     fn get_selected_view(&self) -> &SelectView<String> {
         let box_view = self.view
             .get_child(self.selected_idx as usize)
@@ -199,6 +210,21 @@ impl Multiselect {
 impl ViewWrapper for Multiselect {
     wrap_impl!(self.view: LinearLayout);
 
+    fn wrap_needs_relayout(&self) -> bool {
+        self.get_options_view().is_feeder_updated() || self.view.needs_relayout()
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        // options are reloaded even when layout of inner views is cached
+        self.get_options_view_mut().apply_feeder_updates();
+        self.view.required_size(req)
+    }
+
+    fn wrap_layout(&mut self, size: Vec2) {
+        self.get_options_view_mut().apply_feeder_updates();
+        self.view.layout(size);
+    }
+
     fn wrap_on_event(&mut self, event: Event) -> EventResult {
         match event {
             Event::Key(Key::Enter) => {
@@ -235,3 +261,41 @@ impl ViewWrapper for Multiselect {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
+
+    use feeders::Async;
+
+    fn options(multiselect: &Multiselect) -> Vec<String> {
+        let select = multiselect.get_options_view().get_select_view();
+        (0..select.len())
+            .map(|idx| select.get_item(idx).unwrap().1.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_async_results_are_shown_without_typing() {
+        let (sink, _receiver) = channel();
+        let feeder = Async::new(vec!["option1", "option2"], sink)
+            .debounce(Duration::from_millis(0));
+        let mut multiselect = Multiselect::new(feeder);
+        // loading row
+        assert_eq!(options(&multiselect), vec![""]);
+
+        for _ in 0..100 {
+            if multiselect.get_options_view().is_feeder_updated() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(multiselect.needs_relayout());
+        multiselect.required_size(Vec2::new(40, 10));
+        assert_eq!(options(&multiselect), vec!["option1", "option2"]);
+        assert!(!multiselect.get_options_view().is_feeder_updated());
+    }
+}
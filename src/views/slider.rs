@@ -0,0 +1,306 @@
+use std::cell::Cell;
+use std::cmp::min;
+use std::rc::Rc;
+
+use cursive::Printer;
+use cursive::direction::Direction;
+use cursive::event::{Event, EventResult, Key, MouseButton, MouseEvent};
+use cursive::theme::ColorStyle;
+use cursive::traits::View;
+use cursive::vec::Vec2;
+use cursive::view::ViewWrapper;
+use cursive::views::{DummyView, LinearLayout, TextView};
+
+/// The widest `Bar` which is requested, it gets narrower when there is no space.
+const MAX_BAR_WIDTH: usize = 40;
+
+/// Returns column of `position` (of `count` positions) on bar of `width` columns.
+fn position_to_column(position: usize, count: usize, width: usize) -> usize {
+    if count < 2 || width < 2 {
+        return 0;
+    }
+    let (position, count, width) = (position as u128, count as u128, width as u128);
+    // rounded to the nearest column
+    ((position * (width - 1) + (count - 1) / 2) / (count - 1)) as usize
+}
+
+/// Returns position (of `count` positions) of `column` on bar of `width` columns.
+fn column_to_position(column: usize, count: usize, width: usize) -> usize {
+    if count < 2 || width < 2 {
+        return 0;
+    }
+    let column = min(column, width - 1) as u128;
+    let (count, width) = (count as u128, width as u128);
+    ((column * (count - 1) + (width - 1) / 2) / (width - 1)) as usize
+}
+
+/// Horizontal bar with a knob, scaled to available width.
+///
+/// It replaces Cursive's `SliderView`, which needs a column for each position, so ranges wider
+/// than the screen (like ports 1 to 65535) couldn't be laid out.
+struct Bar {
+    position: Rc<Cell<usize>>,
+    count: usize,
+    width: usize,
+    dragging: bool,
+}
+
+impl Bar {
+    fn slide(&mut self, delta: isize) -> EventResult {
+        let current = self.position.get() as isize;
+        let next = min(
+            current.saturating_add(delta).max(0) as usize,
+            self.count.saturating_sub(1),
+        );
+        if next as isize == current {
+            return EventResult::Ignored;
+        }
+        self.position.set(next);
+        EventResult::Consumed(None)
+    }
+
+    fn page(&self) -> isize {
+        (self.count / 10).max(1) as isize
+    }
+}
+
+impl View for Bar {
+    fn draw(&self, printer: &Printer) {
+        let width = printer.size.x;
+        printer.print_hline((0, 0), width, "-");
+        let color = if printer.focused {
+            ColorStyle::highlight()
+        } else {
+            ColorStyle::highlight_inactive()
+        };
+        let column = position_to_column(self.position.get(), self.count, width);
+        printer.with_color(color, |printer| {
+            printer.print((column, 0), " ");
+        });
+    }
+
+    fn required_size(&mut self, req: Vec2) -> Vec2 {
+        Vec2::new(min(min(self.count, MAX_BAR_WIDTH), req.x).max(1), 1)
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.width = size.x;
+    }
+
+    fn take_focus(&mut self, _: Direction) -> bool {
+        true
+    }
+
+    fn on_event(&mut self, event: Event) -> EventResult {
+        match event {
+            Event::Key(Key::Left) => self.slide(-1),
+            Event::Key(Key::Right) => self.slide(1),
+            Event::Key(Key::PageDown) => {
+                let page = self.page();
+                self.slide(-page)
+            }
+            Event::Key(Key::PageUp) => {
+                let page = self.page();
+                self.slide(page)
+            }
+            Event::Key(Key::Home) => self.slide(-(self.count as isize)),
+            Event::Key(Key::End) => self.slide(self.count as isize),
+            Event::Mouse {
+                event: MouseEvent::Press(MouseButton::Left),
+                position,
+                offset,
+            } if position.fits_in_rect(offset, (self.width, 1)) =>
+            {
+                self.dragging = true;
+                let column = position.x.saturating_sub(offset.x);
+                self.position
+                    .set(column_to_position(column, self.count, self.width));
+                EventResult::Consumed(None)
+            }
+            Event::Mouse {
+                event: MouseEvent::Hold(MouseButton::Left),
+                position,
+                offset,
+            } if self.dragging =>
+            {
+                let column = position.x.saturating_sub(offset.x);
+                self.position
+                    .set(column_to_position(column, self.count, self.width));
+                EventResult::Consumed(None)
+            }
+            Event::Mouse {
+                event: MouseEvent::Release(MouseButton::Left),
+                ..
+            } => {
+                self.dragging = false;
+                EventResult::Ignored
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+/// Shows current value of `Slider`.
+struct ValueLabel {
+    position: Rc<Cell<usize>>,
+    min: i64,
+    step: i64,
+    width: usize,
+}
+
+impl View for ValueLabel {
+    fn draw(&self, printer: &Printer) {
+        let value = self.min + self.position.get() as i64 * self.step;
+        printer.print((0, 0), &format!("{:>width$}", value, width = self.width));
+    }
+
+    fn required_size(&mut self, _: Vec2) -> Vec2 {
+        Vec2::new(self.width, 1)
+    }
+}
+
+/// Number picker with labelled `min`, `max` and current value.
+///
+/// Bar is scaled to available width, so wide ranges (like 1 to 65535) fit on screen, keys
+/// left/right change value by step, page up/down by tenth of range, home/end pick `min`/`max`.
+///
+/// Unlike Cursive's `SliderView` (which needs a column per step) the bar is drawn by this view.
+pub struct Slider {
+    view: LinearLayout,
+
+    position: Rc<Cell<usize>>,
+    min: i64,
+    max: i64,
+    step: i64,
+}
+
+impl Slider {
+    /// Creates a new `Slider` picking numbers from `min` to `max` (inclusive) by `step`.
+    ///
+    /// When `max - min` isn't a multiple of `step`, `max` is rounded down to the last step.
+    /// Range wider than `i64::MAX` is limited to `min + i64::MAX`.
+    pub fn new(min: i64, max: i64, step: i64) -> Self {
+        let step = if step > 0 { step } else { 1 };
+        let max = if max > min { max } else { min };
+        let span = max.checked_sub(min).unwrap_or(i64::MAX);
+        let max = min + span / step * step;
+        let ticks = (span / step) as usize + 1;
+        let position = Rc::new(Cell::new(0));
+        let position_clone = Rc::clone(&position);
+        let width = format!("{}", min).len().max(format!("{}", max).len());
+        let layout = LinearLayout::horizontal()
+            .child(TextView::new(format!("{} ", min)))
+            .child(Bar {
+                position: position_clone,
+                count: ticks,
+                width: 0,
+                dragging: false,
+            })
+            .child(TextView::new(format!(" {}", max)))
+            .child(DummyView)
+            .child(ValueLabel {
+                position: Rc::clone(&position),
+                min,
+                step,
+                width,
+            });
+        Slider {
+            view: layout,
+            position,
+            min,
+            max,
+            step,
+        }
+    }
+
+    /// Sets current value, it's rounded down to closest step and limited to `min`..`max`.
+    pub fn set_value(&mut self, value: i64) {
+        let value = if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        };
+        let position = ((value - self.min) / self.step) as usize;
+        self.position.set(position);
+    }
+
+    /// Sets current value, it's rounded down to closest step and limited to `min`..`max`.
+    ///
+    /// Chainable variant.
+    pub fn value(mut self, value: i64) -> Self {
+        self.set_value(value);
+        self
+    }
+
+    /// Gets current value.
+    pub fn get_value(&self) -> i64 {
+        self.min + self.position.get() as i64 * self.step
+    }
+}
+
+impl ViewWrapper for Slider {
+    wrap_impl!(self.view: LinearLayout);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_scales_positions_to_width() {
+        assert_eq!(position_to_column(0, 65535, 40), 0);
+        assert_eq!(position_to_column(65534, 65535, 40), 39);
+        assert_eq!(position_to_column(32767, 65535, 40), 20);
+        assert_eq!(position_to_column(2, 3, 40), 39);
+        assert_eq!(position_to_column(0, 1, 40), 0);
+
+        assert_eq!(column_to_position(0, 65535, 40), 0);
+        assert_eq!(column_to_position(39, 65535, 40), 65534);
+        assert_eq!(column_to_position(100, 3, 40), 2);
+        for position in 0..5 {
+            assert_eq!(column_to_position(position_to_column(position, 5, 40), 5, 40), position);
+        }
+    }
+
+    #[test]
+    fn test_max_is_rounded_down_to_step() {
+        let mut slider = Slider::new(0, 10, 3);
+        slider.set_value(100);
+        assert_eq!(slider.get_value(), 9);
+        slider.set_value(5);
+        assert_eq!(slider.get_value(), 3);
+
+        let mut slider = Slider::new(1, 65535, 1);
+        slider.set_value(65535);
+        assert_eq!(slider.get_value(), 65535);
+        // labels "1 ", " 65535", separator and value around 40 columns wide bar
+        assert_eq!(slider.required_size(Vec2::new(80, 1)).x, 2 + 40 + 6 + 1 + 5);
+    }
+
+    #[test]
+    fn test_the_widest_range_doesnt_overflow() {
+        let mut slider = Slider::new(i64::MIN, i64::MAX, 1);
+        slider.set_value(i64::MAX);
+        assert_eq!(slider.get_value(), -1);
+        slider.set_value(0);
+        assert_eq!(slider.get_value(), -1);
+        slider.set_value(i64::MIN);
+        assert_eq!(slider.get_value(), i64::MIN);
+
+        let mut slider = Slider::new(-5, i64::MAX, 7);
+        slider.set_value(i64::MAX);
+        assert_eq!(slider.get_value(), -5 + i64::MAX / 7 * 7);
+        slider.required_size(Vec2::new(80, 1));
+        slider.layout(Vec2::new(80, 1));
+        slider.on_event(Event::Key(Key::End));
+        slider.on_event(Event::Key(Key::Right));
+        slider.on_event(Event::Key(Key::PageUp));
+        assert_eq!(slider.get_value(), -5 + i64::MAX / 7 * 7);
+
+        let max = usize::MAX;
+        assert_eq!(position_to_column(max - 1, max, 40), 39);
+        assert_eq!(column_to_position(39, max, 40), max - 1);
+    }
+}
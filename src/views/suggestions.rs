@@ -0,0 +1,133 @@
+use cursive::Printer;
+use cursive::theme::{ColorStyle, Effect};
+use cursive::vec::Vec2;
+use cursive::view::{View, ViewWrapper};
+use cursive::views::SelectView;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use feeders::Suggestion;
+
+const COLUMN_GAP: usize = 2;
+
+/// `SelectView` which draws suggestions with highlighted matched characters.
+///
+/// Suggestions are drawn in columns: category, label, description (the first and the last
+/// are dimmed and skipped when empty).
+pub struct SuggestionList {
+    select: SelectView<String>,
+    suggestions: Vec<Suggestion>,
+}
+
+impl SuggestionList {
+    /// Creates a new empty `SuggestionList`.
+    pub fn new() -> Self {
+        SuggestionList {
+            select: SelectView::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Replaces shown suggestions.
+    pub fn set_suggestions(&mut self, suggestions: Vec<Suggestion>) {
+        self.select.clear();
+        for suggestion in suggestions.iter() {
+            self.select
+                .add_item(suggestion.label.clone(), suggestion.value.clone());
+        }
+        self.suggestions = suggestions;
+    }
+
+    /// Gets view which handles selection.
+    pub fn get_select(&self) -> &SelectView<String> {
+        &self.select
+    }
+
+    /// Gets view which handles selection.
+    pub fn get_select_mut(&mut self) -> &mut SelectView<String> {
+        &mut self.select
+    }
+
+    /// Returns widths of category, label and description columns.
+    fn column_widths(&self) -> (usize, usize, usize) {
+        let width = |f: &Fn(&Suggestion) -> &str| {
+            self.suggestions
+                .iter()
+                .map(|x| f(x).width())
+                .max()
+                .unwrap_or(0)
+        };
+        (
+            width(&|x| &x.category),
+            width(&|x| &x.label),
+            width(&|x| &x.description),
+        )
+    }
+}
+
+fn draw_label(printer: &Printer, suggestion: &Suggestion) {
+    let mut x = 0;
+    for (idx, c) in suggestion.label.chars().enumerate() {
+        let text = c.to_string();
+        if suggestion.positions.contains(&idx) {
+            printer.with_effect(Effect::Bold, |printer| {
+                printer.with_effect(Effect::Underline, |printer| printer.print((x, 0), &text));
+            });
+        } else {
+            printer.print((x, 0), &text);
+        }
+        x += c.width().unwrap_or(0);
+    }
+}
+
+impl ViewWrapper for SuggestionList {
+    wrap_impl!(self.select: SelectView<String>);
+
+    fn wrap_draw(&self, printer: &Printer) {
+        let focus = self.select.selected_id();
+        let (category_width, label_width, _) = self.column_widths();
+        let label_x = if category_width > 0 {
+            category_width + COLUMN_GAP
+        } else {
+            0
+        };
+        let description_x = label_x + label_width + COLUMN_GAP;
+        for (idx, suggestion) in self.suggestions.iter().enumerate() {
+            if idx >= printer.size.y {
+                break;
+            }
+            let printer = printer.offset((0, idx), true);
+            let selected = focus == Some(idx);
+            printer.with_selection(selected, |printer| {
+                printer.print_hline((0, 0), printer.size.x, " ");
+                draw_label(&printer.offset((label_x, 0), true), suggestion);
+            });
+            // selected row keeps its colors, others have dimmed columns
+            let dimmed = if selected {
+                if printer.focused {
+                    ColorStyle::highlight()
+                } else {
+                    ColorStyle::highlight_inactive()
+                }
+            } else {
+                ColorStyle::secondary()
+            };
+            printer.with_color(dimmed, |printer| {
+                printer.print((0, 0), &suggestion.category);
+                printer.print((description_x, 0), &suggestion.description);
+            });
+        }
+    }
+
+    fn wrap_required_size(&mut self, req: Vec2) -> Vec2 {
+        let size = self.select.required_size(req);
+        let (category_width, label_width, description_width) = self.column_widths();
+        let mut width = label_width;
+        if category_width > 0 {
+            width += category_width + COLUMN_GAP;
+        }
+        if description_width > 0 {
+            width += description_width + COLUMN_GAP;
+        }
+        Vec2::new(width.max(size.x), size.y)
+    }
+}